@@ -0,0 +1,274 @@
+/// n! or None if it doesn't fit in a usize
+pub const fn factorial(n: usize) -> Option<usize> {
+    let mut product: usize = 1;
+    let mut k = 2;
+    while k <= n {
+        product = match product.checked_mul(k) {
+            Some(product) => product,
+            None => return None,
+        };
+        k += 1;
+    }
+    Some(product)
+}
+
+/// Number of ways to choose k things from n, or None if it doesn't fit in a usize
+pub fn binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    // Each partial result is itself a binomial no bigger than the answer
+    let mut result: u128 = 1;
+    for i in 0..k {
+        result = result * (n - i) as u128 / (i + 1) as u128;
+        if result > usize::MAX as u128 {
+            return None;
+        }
+    }
+    Some(result as usize)
+}
+
+/// Every permutation of some items, each differing from the one before by a single swap,
+/// in the order of Heap's algorithm, starting with the items as given
+#[derive(Clone, Debug)]
+pub struct Heap<T> {
+    items: Vec<T>,
+    // Heap's algorithm as a loop, counting swaps made at each position
+    counts: Vec<usize>,
+    position: usize,
+    started: bool,
+}
+
+impl<T: Clone> Heap<T> {
+    pub fn new(items: &[T]) -> Self {
+        Self {
+            items: items.to_vec(),
+            counts: vec![0; items.len()],
+            position: 1,
+            started: false,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Heap<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if !self.started {
+            self.started = true;
+            return Some(self.items.clone());
+        }
+        while self.position < self.items.len() {
+            let i = self.position;
+            if self.counts[i] < i {
+                if i.is_multiple_of(2) {
+                    self.items.swap(0, i);
+                } else {
+                    self.items.swap(self.counts[i], i);
+                }
+                self.counts[i] += 1;
+                self.position = 1;
+                return Some(self.items.clone());
+            }
+            self.counts[i] = 0;
+            self.position += 1;
+        }
+        None
+    }
+}
+
+/// Every distinct permutation of some items in lexicographic order, starting with the items
+/// sorted, so repeated items don't give repeated permutations
+#[derive(Clone, Debug)]
+pub struct Lexicographic<T> {
+    items: Vec<T>,
+    done: bool,
+}
+
+impl<T: Clone + Ord> Lexicographic<T> {
+    pub fn new(items: &[T]) -> Self {
+        let mut items = items.to_vec();
+        items.sort();
+        Self { items, done: false }
+    }
+}
+
+impl<T: Clone + Ord> Iterator for Lexicographic<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let current = self.items.clone();
+        // Find the last ascent, swap it with the last item larger than it, and reverse the
+        // descending tail after it, if there's no ascent this is the last permutation
+        match self.items.windows(2).rposition(|w| w[0] < w[1]) {
+            Some(i) => {
+                let j = self.items.iter().rposition(|item| *item > self.items[i]);
+                self.items.swap(i, j.expect("The item after i is larger"));
+                self.items[i + 1..].reverse();
+            }
+            None => self.done = true,
+        }
+        Some(current)
+    }
+}
+
+/// Every way to choose k of some items, keeping them in their original order, with the
+/// choices in lexicographic order of position
+#[derive(Clone, Debug)]
+pub struct Combinations<T> {
+    items: Vec<T>,
+    // Positions of the chosen items, or None when every choice has been made
+    chosen: Option<Vec<usize>>,
+}
+
+impl<T: Clone> Combinations<T> {
+    pub fn new(items: &[T], k: usize) -> Self {
+        Self {
+            items: items.to_vec(),
+            chosen: (k <= items.len()).then(|| (0..k).collect()),
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let chosen = self.chosen.as_mut()?;
+        let current = chosen.iter().map(|&n| self.items[n].clone()).collect();
+        let (n, k) = (self.items.len(), chosen.len());
+        // Advance the last position which isn't already as late as it could be
+        match (0..k).rev().find(|&i| chosen[i] < n - k + i) {
+            Some(i) => {
+                chosen[i] += 1;
+                for j in i + 1..k {
+                    chosen[j] = chosen[j - 1] + 1;
+                }
+            }
+            None => self.chosen = None,
+        }
+        Some(current)
+    }
+}
+
+/// Every subset of some items, keeping them in their original order, starting with the
+/// empty set and counting in binary with the first item as the least significant bit
+#[derive(Clone, Debug)]
+pub struct PowerSet<T> {
+    items: Vec<T>,
+    // Which items are in the next subset, or None when every subset has been seen
+    members: Option<Vec<bool>>,
+}
+
+impl<T: Clone> PowerSet<T> {
+    pub fn new(items: &[T]) -> Self {
+        Self {
+            items: items.to_vec(),
+            members: Some(vec![false; items.len()]),
+        }
+    }
+}
+
+impl<T: Clone> Iterator for PowerSet<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let members = self.members.as_mut()?;
+        let current = self
+            .items
+            .iter()
+            .zip(members.iter())
+            .filter(|(_, &member)| member)
+            .map(|(item, _)| item.clone())
+            .collect();
+        match members.iter().position(|&member| !member) {
+            Some(n) => {
+                members[..n].fill(false);
+                members[n] = true;
+            }
+            None => self.members = None,
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::combinatorics::*;
+
+    #[test]
+    fn counting() {
+        assert_eq!(factorial(0), Some(1));
+        assert_eq!(factorial(10), Some(3628800));
+        assert_eq!(factorial(20), Some(2432902008176640000));
+        assert_eq!(factorial(21), None);
+        assert_eq!(binomial(5, 2), Some(10));
+        assert_eq!(binomial(5, 7), Some(0));
+        assert_eq!(binomial(100, 50), None);
+        assert_eq!(binomial(66, 33), Some(7219428434016265740));
+    }
+
+    #[test]
+    fn heap() {
+        let all: Vec<Vec<char>> = Heap::new(&['a', 'b', 'c', 'd', 'e']).collect();
+        assert_eq!(all.len(), 120);
+        let mut t = ['a', 'b', 'c', 'd', 'e'];
+        for (n, p) in all.iter().enumerate() {
+            crate::heap(&mut t, n);
+            assert_eq!(p, &t);
+        }
+        assert_eq!(Heap::<u8>::new(&[]).count(), 1);
+        assert_eq!(Heap::new(&[0; 12]).take(1000).count(), 1000);
+    }
+
+    #[test]
+    fn lexicographic() {
+        let all: Vec<Vec<u8>> = Lexicographic::new(&[3, 1, 2]).collect();
+        assert_eq!(
+            all,
+            [
+                [1, 2, 3],
+                [1, 3, 2],
+                [2, 1, 3],
+                [2, 3, 1],
+                [3, 1, 2],
+                [3, 2, 1]
+            ]
+        );
+        assert_eq!(Lexicographic::new(&[1, 1, 2]).count(), 3);
+    }
+
+    #[test]
+    fn combinations() {
+        let all: Vec<Vec<u8>> = Combinations::new(&[1, 2, 3, 4], 2).collect();
+        assert_eq!(all, [[1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]]);
+        assert_eq!(Combinations::new(&[1, 2], 0).count(), 1);
+        assert_eq!(Combinations::new(&[1, 2], 3).count(), 0);
+        assert_eq!(
+            Combinations::new(&[0; 20], 7).count(),
+            binomial(20, 7).unwrap()
+        );
+    }
+
+    #[test]
+    fn power_set() {
+        let all: Vec<Vec<u8>> = PowerSet::new(&[1, 2, 3]).collect();
+        assert_eq!(
+            all,
+            [
+                vec![],
+                vec![1],
+                vec![2],
+                vec![1, 2],
+                vec![3],
+                vec![1, 3],
+                vec![2, 3],
+                vec![1, 2, 3]
+            ]
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::io;
+use std::ops::Range;
+
+/// Problems reading or interpreting puzzle input
+#[derive(Debug)]
+pub enum Error {
+    /// The input couldn't be read from its source, e.g. a missing file
+    Io { source: String, error: io::Error },
+    /// Some text in the input isn't what was expected
+    Parse(ParseError),
+}
+
+impl Error {
+    pub fn io(source: impl fmt::Display, error: io::Error) -> Self {
+        Self::Io {
+            source: source.to_string(),
+            error,
+        }
+    }
+
+    /// The whole of this line of input (counting from 1) isn't what was expected
+    pub fn parse(line: usize, text: &str, expected: &'static str) -> Self {
+        Self::Parse(ParseError::new(text, text, expected).line(line))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { source, error } => write!(f, "Unable to read {source}: {error}"),
+            Self::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// Where some text was found that isn't what was expected, for the FromStr impls of puzzle
+/// input types
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of input, counting from 1, or 0 if the line isn't known yet
+    pub line: usize,
+    /// Byte columns within the text, counting from 0, of whatever was found instead
+    pub span: Range<usize>,
+    /// The text being parsed, usually a whole line of input
+    pub text: String,
+    pub expected: &'static str,
+}
+
+// Where part lies within text, if it is a slice of that text
+fn offset(text: &str, part: &str) -> Option<usize> {
+    let start = (part.as_ptr() as usize).checked_sub(text.as_ptr() as usize)?;
+    (start + part.len() <= text.len()).then_some(start)
+}
+
+impl ParseError {
+    /// Expected something in text, but found this part of it, which should be a slice of the
+    /// text, or else the whole text is blamed
+    pub fn new(text: &str, found: &str, expected: &'static str) -> Self {
+        let span = match offset(text, found) {
+            Some(start) => start..start + found.len(),
+            None => 0..text.len(),
+        };
+        Self {
+            line: 0,
+            span,
+            text: String::from(text),
+            expected,
+        }
+    }
+
+    /// Expected something in text, which may be several lines, but found this slice of it,
+    /// which is blamed within its own line, counting lines from 1 at the start of the text
+    pub fn at(text: &str, found: &str, expected: &'static str) -> Self {
+        let Some(start) = offset(text, found) else {
+            return Self::new(text, found, expected).line(1);
+        };
+        let from = text[..start].rfind('\n').map_or(0, |n| n + 1);
+        let line = text[from..].lines().next().unwrap_or_default();
+        let number = text[..start].matches('\n').count() + 1;
+        Self::new(line, found, expected).line(number)
+    }
+
+    /// Expected something more at the end of text
+    pub fn end(text: &str, expected: &'static str) -> Self {
+        Self::new(text, &text[text.len()..], expected)
+    }
+
+    /// This error, but on the given line of input
+    pub fn line(self, line: usize) -> Self {
+        Self { line, ..self }
+    }
+
+    /// This error, which was found in some text starting on the given line of input, moved so
+    /// that its line counts from the start of the whole input instead
+    pub fn starting_at(self, first: usize) -> Self {
+        Self {
+            line: first + self.line.max(1) - 1,
+            ..self
+        }
+    }
+
+    /// Expected something but found this character, see Map's FromStr, which says where it is
+    pub fn char(ch: char, expected: &'static str) -> Self {
+        let text = ch.to_string();
+        Self::new(&text, &text, expected)
+    }
+
+    /// This error, which was found parsing the inner slice of the outer text, moved so that
+    /// it is relative to the outer text instead
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        let Some(start) = offset(outer, inner) else {
+            return self;
+        };
+        Self {
+            span: start + self.span.start..start + self.span.end,
+            text: String::from(outer),
+            ..self
+        }
+    }
+
+    /// Whatever was found instead of what was expected
+    pub fn found(&self) -> &str {
+        self.text.get(self.span.clone()).unwrap_or_default()
+    }
+
+    /// A description of the error, then the line of input with carets under the problem
+    pub fn excerpt(&self) -> String {
+        let number = self.line.to_string();
+        let padding = " ".repeat(number.len());
+        let before = self.text.get(..self.span.start).unwrap_or_default();
+        let indent = " ".repeat(before.chars().count());
+        let carets = "^".repeat(self.found().chars().count().max(1));
+        format!(
+            "{self}\n{padding} |\n{number} | {}\n{padding} | {indent}{carets}",
+            self.text
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let column = self.span.start + 1;
+        let found = self.found();
+        if found.is_empty() {
+            write!(
+                f,
+                "Line {}, column {column}: expected {} but found nothing",
+                self.line, self.expected
+            )
+        } else {
+            write!(
+                f,
+                "Line {}, column {column}: expected {} but found {found:?}",
+                self.line, self.expected
+            )
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<Infallible> for ParseError {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ParseError;
+
+    #[test]
+    fn excerpt() {
+        let line = "Game 7: 3 blue, 2 purple";
+        let round = &line[8..];
+        let colour = &round[10..];
+        let e = ParseError::new(round, colour, "a colour")
+            .within(line, round)
+            .line(4);
+        assert_eq!(e.span, 18..24);
+        assert_eq!(e.found(), "purple");
+        assert_eq!(
+            e.excerpt(),
+            "Line 4, column 19: expected a colour but found \"purple\"\n  |\n4 | Game 7: 3 blue, 2 purple\n  |                   ^^^^^^"
+        );
+
+        let e = ParseError::end("(AAA, BBB", ")").line(12);
+        assert_eq!(
+            e.to_string(),
+            "Line 12, column 10: expected ) but found nothing"
+        );
+    }
+}
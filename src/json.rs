@@ -0,0 +1,134 @@
+use std::fmt;
+
+/// Just enough JSON to describe Reports for scripts, written out by Display
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    /// Non-finite numbers can't be represented, so they're written as null
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    /// Members are written in this order
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// An Object from pairs of names and values
+    pub fn object<'a>(members: impl IntoIterator<Item = (&'a str, Json)>) -> Self {
+        Self::Object(
+            members
+                .into_iter()
+                .map(|(name, value)| (String::from(name), value))
+                .collect(),
+        )
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Self {
+        Self::String(String::from(s))
+    }
+}
+
+impl From<String> for Json {
+    fn from(s: String) -> Self {
+        Self::String(s)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(n: f64) -> Self {
+        Self::Number(n)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(n: usize) -> Self {
+        Self::Number(n as f64)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(b: bool) -> Self {
+        Self::Bool(b)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+fn escape(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    f.write_str("\"")
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Bool(b) => write!(f, "{b}"),
+            Self::Number(n) if n.is_finite() => write!(f, "{n}"),
+            Self::Number(_) => f.write_str("null"),
+            Self::String(s) => escape(f, s),
+            Self::Array(values) => {
+                f.write_str("[")?;
+                for (k, value) in values.iter().enumerate() {
+                    if k > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_str("]")
+            }
+            Self::Object(members) => {
+                f.write_str("{")?;
+                for (k, (name, value)) in members.iter().enumerate() {
+                    if k > 0 {
+                        f.write_str(",")?;
+                    }
+                    escape(f, name)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::Json;
+
+    #[test]
+    fn encode() {
+        let json = Json::object([
+            ("day", Json::from(5)),
+            ("answer", Json::from("Say \"hi\"\n\u{1}")),
+            ("time", Json::from(0.25)),
+            ("missing", Json::from(None::<&str>)),
+            (
+                "list",
+                Json::Array(vec![Json::Bool(true), Json::Number(f64::NAN)]),
+            ),
+        ]);
+        assert_eq!(
+            json.to_string(),
+            r#"{"day":5,"answer":"Say \"hi\"\n\u0001","time":0.25,"missing":null,"list":[true,null]}"#
+        );
+    }
+}
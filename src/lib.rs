@@ -1,22 +1,129 @@
+/// Register the modules for each day, giving a Part for each of its parts a and b
+///
+/// Each part is solved from the input as parsed by the day's parse function, which returns a
+/// ParseError if the input is malformed, unless the day
+/// names another parser for that part, e.g. `day06 { parse_b: parse_kerned }`. A day may also
+/// name alternative solvers for either part, each a function in its module, e.g.
+/// `day12 { a: [memo => a_memo] }` so that `day12::a_memo` is the "memo" variant of 12a.
+/// Where the puzzle's example needs a different solver, e.g. with a smaller search area, the
+/// day names it for the fixtures, e.g. `day24 { example_a: a_example }`
 #[macro_export]
 macro_rules! days {
-    ($obj:expr, $($day:ident,)+) => {
-        days!($obj, $(days!($day a) => $day::a(), days!($day b) => $day::b()),*, _ => { println!("{} not available yet", $obj); });
+    ($($day:ident $({
+        $(a: [$($a_name:ident => $a_fn:ident),* $(,)?] $(,)?)?
+        $(b: [$($b_name:ident => $b_fn:ident),* $(,)?] $(,)?)?
+        $(parse_a: $parse_a:ident $(,)?)?
+        $(parse_b: $parse_b:ident $(,)?)?
+        $(example_a: $example_a:ident $(,)?)?
+        $(example_b: $example_b:ident $(,)?)?
+    })?),+ $(,)?) => {
+        [$(
+            $crate::runner::Part {
+                day: stringify!($day),
+                part: 'a',
+                solver: |ctxt, times| {
+                    let parse = $crate::parser!($day $($(, $parse_a)?)?);
+                    $crate::runner::timed(ctxt, parse, $day::a, times)
+                },
+                variants: $crate::variants!(
+                    $crate::parser!($day $($(, $parse_a)?)?), $day;
+                    $($($($a_name => $a_fn),*)?)?
+                ),
+                example: $crate::example!(
+                    $crate::parser!($day $($(, $parse_a)?)?), $day; $($($example_a)?)?
+                ),
+            },
+            $crate::runner::Part {
+                day: stringify!($day),
+                part: 'b',
+                solver: |ctxt, times| {
+                    let parse = $crate::parser!($day $($(, $parse_b)?)?);
+                    $crate::runner::timed(ctxt, parse, $day::b, times)
+                },
+                variants: $crate::variants!(
+                    $crate::parser!($day $($(, $parse_b)?)?), $day;
+                    $($($($b_name => $b_fn),*)?)?
+                ),
+                example: $crate::example!(
+                    $crate::parser!($day $($(, $parse_b)?)?), $day; $($($example_b)?)?
+                ),
+            },
+        )+]
     };
-    ($obj:expr, $($day:ident),+) => {
-        days!($obj, $($day,)+);
+}
+
+/// The parser named for a part in days!, or else the day's parse function
+#[doc(hidden)]
+#[macro_export]
+macro_rules! parser {
+    ($day:ident) => {
+        $day::parse
     };
-    ($s:ident a) => {
-        concat!(stringify!($s), 'a')
+    ($day:ident, $parse:ident) => {
+        $day::$parse
     };
-    ($s:ident b) => {
-        concat!(stringify!($s), 'b')
+}
+
+/// The variants named for a part in days!, each solving the input from this parser
+#[doc(hidden)]
+#[macro_export]
+macro_rules! variants {
+    ($parse:expr, $day:ident; $($name:ident => $solver:ident),*) => {
+        &[$($crate::runner::Variant {
+            name: stringify!($name),
+            solver: |ctxt, times| $crate::runner::timed(ctxt, $parse, $day::$solver, times),
+        },)*]
+    };
+}
+
+/// The solver named for a part's example in days!, solving the input from this parser
+#[doc(hidden)]
+#[macro_export]
+macro_rules! example {
+    ($parse:expr, $day:ident;) => {
+        None
+    };
+    ($parse:expr, $day:ident; $solver:ident) => {
+        Some(|ctxt, times| $crate::runner::timed(ctxt, $parse, $day::$solver, times))
+    };
+}
+
+/// Generate a #[test] for each part a and b of each day, checking the example fixture for
+/// that part (see runner::fixture) using the registered Year
+///
+/// A part whose example doesn't suit it is `ignore`d, e.g. `day20 { b: ignore }`
+#[macro_export]
+macro_rules! fixtures {
+    ($year:path; $($day:ident $({
+        $(a: $a:ident $(,)?)?
+        $(b: $b:ident $(,)?)?
+    })?),+ $(,)?) => {
+        $(
+            mod $day {
+                $crate::fixture!($year; $day a $($($a)?)?);
+                $crate::fixture!($year; $day b $($($b)?)?);
+            }
+        )+
+    };
+}
+
+/// The #[test] for one part's example in fixtures!
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fixture {
+    ($year:path; $day:ident $part:ident) => {
+        #[test]
+        fn $part() {
+            $crate::runner::fixture(&$year, concat!(stringify!($day), stringify!($part)));
+        }
+    };
+    ($year:path; $day:ident $part:ident ignore) => {
+        #[test]
+        #[ignore = "the example doesn't suit this part"]
+        fn $part() {
+            $crate::runner::fixture(&$year, concat!(stringify!($day), stringify!($part)));
+        }
     };
-    ($obj:expr, $($matcher:pat => $result:expr),*) => {
-       match $obj {
-           $($matcher => $result),*
-       }
-    }
 }
 
 pub struct Contents {
@@ -24,29 +131,54 @@ pub struct Contents {
 }
 
 impl<'t> Contents {
-    pub fn lines(&'t self) -> impl Iterator<Item = &str> + 't {
+    pub fn lines(&'t self) -> impl Iterator<Item = &'t str> + 't {
         self.text.lines()
     }
 
+    /// Each line as a number, lines which aren't numbers are treated as zero, see try_numbers
     pub fn numbers(&self) -> impl Iterator<Item = isize> + '_ {
         self.lines().map(|l| l.parse::<isize>().unwrap_or(0))
     }
 
+    /// Each line as a number, or an Error identifying the first line which isn't a number
+    pub fn try_numbers(&self) -> impl Iterator<Item = Result<isize, Error>> + '_ {
+        self.lines()
+            .enumerate()
+            .map(|(n, l)| l.parse().map_err(|_| Error::parse(n + 1, l, "a number")))
+    }
+
     pub fn binary(&self) -> impl Iterator<Item = isize> + '_ {
         self.lines()
             .filter_map(|l| isize::from_str_radix(l, 2).ok())
     }
 
     pub fn digits(&self) -> impl Iterator<Item = u32> + '_ {
-        self.text.trim().chars().map(|c| c.to_digit(10).unwrap())
+        self.try_digits()
+            .map(|d| d.unwrap_or_else(|e| panic!("{e}")))
     }
 
-    pub fn list(&'t self) -> impl Iterator<Item = &str> + 't {
+    pub fn try_digits(&self) -> impl Iterator<Item = Result<u32, Error>> + '_ {
+        let value = self.value();
+        value.char_indices().map(|(i, c)| {
+            c.to_digit(10)
+                .ok_or_else(|| self.misplaced(&value[i..i + c.len_utf8()], "a digit"))
+        })
+    }
+
+    pub fn list(&'t self) -> impl Iterator<Item = &'t str> + 't {
         self.text.trim().split(',')
     }
 
     pub fn list_numbers(&self) -> impl Iterator<Item = isize> + '_ {
-        self.list().map(|n| n.parse().unwrap())
+        self.try_list_numbers()
+            .map(|n| n.unwrap_or_else(|e| panic!("{e}")))
+    }
+
+    pub fn try_list_numbers(&self) -> impl Iterator<Item = Result<isize, Error>> + '_ {
+        self.list().map(|n| {
+            n.parse()
+                .map_err(|_| self.misplaced(n, "a number in the list"))
+        })
     }
 
     pub fn value(&self) -> &str {
@@ -54,32 +186,375 @@ impl<'t> Contents {
     }
 
     pub fn number(&self) -> isize {
-        self.value().parse().unwrap()
+        self.try_number().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub fn try_number(&self) -> Result<isize, Error> {
+        let value = self.value();
+        value.parse().map_err(|_| self.misplaced(value, "a number"))
+    }
+
+    /// Which line, counting from 1, this slice of the text starts on
+    pub fn line_of(&self, part: &str) -> usize {
+        let start = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        self.text[..start].matches('\n').count() + 1
+    }
+
+    // Expected something in the text, but found this slice of it, blaming it within its line
+    fn misplaced(&self, found: &str, expected: &'static str) -> Error {
+        Error::Parse(ParseError::at(&self.text, found, expected))
+    }
+
+    /// Each block of lines separated by one or more blank lines
+    pub fn sections(&'t self) -> impl Iterator<Item = &'t str> + 't {
+        let mut sections = Vec::new();
+        let mut start: Option<usize> = None;
+        let mut end = 0;
+        let mut offset = 0;
+        for line in self.text.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                if let Some(start) = start.take() {
+                    sections.push(&self.text[start..end]);
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + line.trim_end_matches(['\r', '\n']).len();
+            }
+            offset += line.len();
+        }
+        if let Some(start) = start {
+            sections.push(&self.text[start..end]);
+        }
+        sections.into_iter()
+    }
+
+    /// Every signed integer in this part of the text, e.g. a section, see the ints function,
+    /// with any error on its line of the whole text
+    pub fn ints<T: FromStr>(
+        &'t self,
+        part: &'t str,
+    ) -> impl Iterator<Item = Result<T, ParseError>> + 't {
+        integers(&self.text, part)
+    }
+
+    /// The whole text as a Map, with each character converted to a T, or where the first
+    /// character which isn't a T is
+    pub fn grid<T>(&self) -> Result<map::Map<T>, ParseError>
+    where
+        T: TryFrom<char> + Copy + Default,
+        T::Error: Into<ParseError>,
+    {
+        let value = self.value();
+        value
+            .parse()
+            .map_err(|e: ParseError| e.starting_at(self.line_of(value)))
+    }
+
+    /// Each line parsed as a T, or a ParseError for every line which couldn't be parsed
+    ///
+    /// Where T's errors are a ParseError they say where in the line the problem is, otherwise
+    /// the whole line is blamed
+    pub fn parse_lines<T>(&self) -> Result<Vec<T>, Vec<ParseError>>
+    where
+        T: FromStr,
+        T::Err: Any,
+    {
+        let name = short_name::<T>();
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        for (n, line) in self.lines().enumerate() {
+            match line.parse() {
+                Ok(value) => values.push(value),
+                Err(e) => {
+                    let e: &dyn Any = &e;
+                    let e = match e.downcast_ref::<ParseError>() {
+                        Some(e) => e.clone(),
+                        None => ParseError::new(line, line, name),
+                    };
+                    errors.push(e.line(n + 1));
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(values)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Each line parsed as a T, or the error from the first line which couldn't be parsed
+    pub fn parsed<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Any,
+    {
+        self.parse_lines()
+            .map_err(|mut errors| errors.swap_remove(0))
     }
 }
 
+use std::any::Any;
+
+// The name of a type without its path, e.g. "u8" or "Brick", for what was expected
+fn short_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+use std::str::FromStr;
+
+/// Every signed integer in some text, e.g. a line or a section, ignoring everything else
+///
+/// A minus sign is only part of a number if it is directly before the digits and doesn't
+/// follow a letter or digit, so "x=-3" has -3 but "1-2" has 1 and 2
+///
+/// Where an integer doesn't fit in a T, there's an error on its line, counting from 1 at the
+/// start of the text
+pub fn ints<T: FromStr>(text: &str) -> impl Iterator<Item = Result<T, ParseError>> + '_ {
+    integers(text, text)
+}
+
+// Every signed integer in the part, with errors placed within the whole text it is part of
+fn integers<'t, T: FromStr>(
+    whole: &'t str,
+    text: &'t str,
+) -> impl Iterator<Item = Result<T, ParseError>> + 't {
+    let name = short_name::<T>();
+    let bytes = text.as_bytes();
+    let mut k = 0;
+    std::iter::from_fn(move || {
+        while k < bytes.len() {
+            let start = k;
+            let negative = bytes[k] == b'-'
+                && bytes.get(k + 1).is_some_and(u8::is_ascii_digit)
+                && (k == 0 || !bytes[k - 1].is_ascii_alphanumeric());
+            if negative {
+                k += 1;
+            }
+            if bytes[k].is_ascii_digit() {
+                while k < bytes.len() && bytes[k].is_ascii_digit() {
+                    k += 1;
+                }
+                let number = &text[start..k];
+                return Some(
+                    number
+                        .parse()
+                        .map_err(|_| ParseError::at(whole, number, name)),
+                );
+            }
+            k += 1;
+        }
+        None
+    })
+}
+
+/// Each field of some text separated by sep, trimmed and parsed as a T, or an error where a
+/// field isn't a T, on its line counting from 1 at the start of the text
+///
+/// Unlike ints nothing is ignored, except that where sep is whitespace a run of whitespace
+/// separates just two fields
+pub fn fields<T: FromStr>(
+    text: &str,
+    sep: char,
+) -> impl Iterator<Item = Result<T, ParseError>> + '_ {
+    let name = short_name::<T>();
+    text.split(sep)
+        .map(str::trim)
+        .filter(move |field| !(sep.is_whitespace() && field.is_empty()))
+        .map(move |field| field.parse().map_err(|_| ParseError::at(text, field, name)))
+}
+
 use std::fs;
 
 pub fn readfile(filename: &str) -> Contents {
-    let text = fs::read_to_string(filename).expect("Unable to read file");
-    Contents { text }
+    try_readfile(filename).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_readfile(filename: &str) -> Result<Contents, Error> {
+    let text = fs::read_to_string(filename).map_err(|e| Error::io(filename, e))?;
+    Ok(Contents { text })
+}
+
+use std::fmt::Display;
+
+/// Each part of a day's puzzle is a Solver, which works out a typed Answer from the parsed
+/// puzzle input rather than printing it, so that answers can be inspected, compared or timed
+pub trait Solver<I: ?Sized> {
+    type Answer: Display;
+
+    fn solve(&self, input: &I) -> Self::Answer;
+}
+
+impl<F, I, A> Solver<I> for F
+where
+    F: Fn(&I) -> A,
+    I: ?Sized,
+    A: Display,
+{
+    type Answer = A;
+
+    fn solve(&self, input: &I) -> A {
+        self(input)
+    }
+}
+
+use std::path::PathBuf;
+
+/// Where the puzzle input for a day comes from
+#[derive(Clone, Debug, Default)]
+pub enum Input {
+    /// A file named for the day (e.g. "05") in the current directory
+    #[default]
+    Here,
+    /// A file named for the day (e.g. "inputs/05.txt") in this directory, or if there is one, a
+    /// file named for the part (e.g. "inputs/05b.txt") where parts need different input
+    Directory(PathBuf),
+    /// Exactly this file, regardless of the day
+    File(PathBuf),
+    /// Standard input, regardless of the day
+    Stdin,
 }
 
+impl Input {
+    /// Environment variable naming a directory of inputs
+    pub const VARIABLE: &'static str = "SKY_INPUTS";
+
+    /// Inputs from the directory named by the SKY_INPUTS environment variable, if it is set
+    pub fn from_env() -> Self {
+        match std::env::var_os(Self::VARIABLE) {
+            Some(dir) => Self::Directory(dir.into()),
+            None => Self::Here,
+        }
+    }
+
+    /// An explicit path, where "-" means standard input
+    pub fn path(path: &str) -> Self {
+        if path == "-" {
+            Self::Stdin
+        } else {
+            Self::File(path.into())
+        }
+    }
+
+    /// Inputs for the given year, which are in a directory named for the year (e.g.
+    /// "inputs/2023/05.txt") if there is one, or else where they would be for any year
+    pub fn year(&self, year: u16) -> Self {
+        let dir = match self {
+            Self::Here => PathBuf::from(year.to_string()),
+            Self::Directory(dir) => dir.join(year.to_string()),
+            Self::File(_) | Self::Stdin => return self.clone(),
+        };
+        if dir.is_dir() {
+            Self::Directory(dir)
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Where the recorded answers for the given year are kept, so that years don't share
+    /// answers, in the directory for the year if there is one (e.g. "inputs/2023/answers.txt")
+    /// or else named for the year alongside the inputs (e.g. "inputs/answers-2023.txt")
+    pub fn answers(&self, year: u16) -> PathBuf {
+        let dir = match self {
+            Self::Directory(dir) => dir.clone(),
+            Self::Here | Self::File(_) | Self::Stdin => PathBuf::new(),
+        };
+        let inputs = dir.join(year.to_string());
+        if inputs.is_dir() {
+            inputs.join("answers.txt")
+        } else {
+            dir.join(format!("answers-{year}.txt"))
+        }
+    }
+
+    /// Whether this is one input whatever the day, which is only suitable for a single day
+    pub fn is_single(&self) -> bool {
+        matches!(self, Self::File(_) | Self::Stdin)
+    }
+
+    /// Contents of the input for a day or part of a day, e.g. "day05" or "day05b"
+    pub fn contents(&self, day: &str) -> Contents {
+        self.try_contents(day).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub fn try_contents(&self, day: &str) -> Result<Contents, Error> {
+        let name = day.strip_prefix("day").unwrap_or(day);
+        let number = name.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+        let text = match self {
+            Self::Here => return try_readfile(number),
+            Self::Directory(dir) => {
+                let mut path = dir.join(format!("{name}.txt"));
+                if !path.exists() {
+                    path = dir.join(format!("{number}.txt"));
+                }
+                fs::read_to_string(&path).map_err(|e| Error::io(path.display(), e))?
+            }
+            Self::File(path) => {
+                fs::read_to_string(path).map_err(|e| Error::io(path.display(), e))?
+            }
+            Self::Stdin => stdin().map_err(|e| Error::io("stdin", e))?,
+        };
+        Ok(Contents { text })
+    }
+}
+
+// Standard input can only be read once, so it is kept for every part which asks for it
+fn stdin() -> Result<String, std::io::Error> {
+    static STDIN: OnceLock<Result<String, (std::io::ErrorKind, String)>> = OnceLock::new();
+    STDIN
+        .get_or_init(|| {
+            std::io::read_to_string(std::io::stdin()).map_err(|e| (e.kind(), e.to_string()))
+        })
+        .clone()
+        .map_err(|(kind, message)| std::io::Error::new(kind, message))
+}
+
+use std::sync::OnceLock;
+
 use core::ops::ControlFlow;
+use std::cell::Cell;
+use std::cmp::Reverse;
 use std::collections::hash_map;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::Hash;
+use std::time::Instant;
 
 /// T is a type for an invariant, such as a map
 pub trait State<T>: Copy + Eq + Hash {
     fn describe(&self, invariant: &T) -> String;
     fn next(&self, invariant: &T) -> Vec<Self>;
 
+    /// Like next, but with the cost of moving to each State, by default every step costs 1
+    fn next_weighted(&self, invariant: &T) -> Vec<(Self, usize)> {
+        self.next(invariant).into_iter().map(|s| (s, 1)).collect()
+    }
+
     /// Search novel states, report each novel state to a Report function
     /// the Report function is given the novel state, invariant and a count of steps
     /// if the Report function returns Continue this state will be used to
     /// generate future new states, if Break it is ignored
+    ///
+    /// Panics if the current Budget is exceeded, see Budget::enforce
     fn search<R, B>(initial: Self, report: R, invariant: &T) -> Vec<B>
+    where
+        R: Fn(&Self, &T, usize) -> ControlFlow<B>,
+    {
+        match Self::search_within(initial, report, &Budget::current(), invariant) {
+            Ok(found) => found,
+            Err(limit) => panic!(
+                "Gave up searching from {}: the search exceeded {limit}",
+                initial.describe(invariant)
+            ),
+        }
+    }
+
+    /// As search, but giving up if the Budget is exceeded before every novel state is seen
+    fn search_within<R, B>(
+        initial: Self,
+        report: R,
+        budget: &Budget,
+        invariant: &T,
+    ) -> Result<Vec<B>, Limit>
     where
         R: Fn(&Self, &T, usize) -> ControlFlow<B>,
     {
@@ -90,6 +565,7 @@ pub trait State<T>: Copy + Eq + Hash {
         let mut steps = 0;
 
         while !current.is_empty() {
+            budget.check(seen.len(), steps)?;
             let mut next: Vec<Self> = Vec::new();
             for state in current {
                 match report(&state, invariant, steps) {
@@ -110,43 +586,47 @@ pub trait State<T>: Copy + Eq + Hash {
             steps += 1;
             current = next;
         }
-        found
+        Ok(found)
     }
 
-    /// Minimum Steps from initial until predicate is true
+    /// Minimum Steps from initial until predicate is true, within the current Budget
     fn steps<P>(initial: Self, predicate: P, invariant: &T) -> usize
     where
         P: Fn(&Self) -> bool,
         Self: std::fmt::Debug,
     {
-        if predicate(&initial) {
-            return 0;
+        match Self::steps_within(initial, predicate, &Budget::current(), invariant) {
+            Ok(steps) => steps,
+            Err(e) => panic!("No route to achieve predicate on {initial:?}, {e}"),
         }
+    }
 
-        let mut seen: HashSet<Self> = HashSet::new();
-        seen.insert(initial);
-        let mut current: Vec<Self> = vec![initial];
-        let mut steps = 0;
+    /// As steps, but explaining why the predicate couldn't be achieved, either because every
+    /// reachable state was seen or because the Budget was exceeded first
+    fn steps_within<P>(
+        initial: Self,
+        predicate: P,
+        budget: &Budget,
+        invariant: &T,
+    ) -> Result<usize, Unfound>
+    where
+        P: Fn(&Self) -> bool,
+    {
+        let expand = |frontier: &[Self]| frontier.iter().map(|s| s.next(invariant)).collect();
+        fewest(initial, predicate, expand, budget)
+    }
 
-        loop {
-            let mut next: Vec<Self> = Vec::new();
-            for state in current {
-                let more = state.next(invariant);
-                for state in more {
-                    if predicate(&state) {
-                        return steps + 1;
-                    }
-                    if !seen.contains(&state) {
-                        seen.insert(state);
-                        next.push(state);
-                    }
-                }
-            }
-            if next.is_empty() {
-                panic!("No route to achieve predicate on {initial:?}");
-            }
-            steps += 1;
-            current = next;
+    /// As steps, but with each frontier of the search spread across threads
+    fn steps_parallel<P>(initial: Self, predicate: P, invariant: &T) -> usize
+    where
+        P: Fn(&Self) -> bool,
+        Self: std::fmt::Debug + Send + Sync,
+        T: Sync,
+    {
+        let expand = |frontier: &[Self]| next_parallel(frontier, invariant);
+        match fewest(initial, predicate, expand, &Budget::current()) {
+            Ok(steps) => steps,
+            Err(e) => panic!("No route to achieve predicate on {initial:?}, {e}"),
         }
     }
 
@@ -158,40 +638,186 @@ pub trait State<T>: Copy + Eq + Hash {
         Self::steps(initial, |&s| s == goal, invariant)
     }
 
-    /// Possible states after up to steps taken
-    fn count(initial: Self, steps: usize, invariant: &T) -> usize {
-        let mut seen: HashSet<Self> = HashSet::new();
-        seen.insert(initial);
-        let mut current: Vec<Self> = vec![initial];
+    /// As best, but searching backwards from the goal too, using previous to find the States
+    /// which have a given State among their next States
+    fn best_bidirectional<R>(initial: Self, goal: Self, previous: R, invariant: &T) -> usize
+    where
+        R: Fn(&Self, &T) -> Vec<Self>,
+        Self: std::fmt::Debug,
+    {
+        if initial == goal {
+            return 0;
+        }
+        let mut forward = Side::new(initial);
+        let mut backward = Side::new(goal);
+        loop {
+            if forward.current.is_empty() || backward.current.is_empty() {
+                panic!("No route from {initial:?} to {goal:?}");
+            }
+            // Expand whichever side has the smaller frontier
+            let meet = if forward.current.len() <= backward.current.len() {
+                forward.expand(&backward, |s| s.next(invariant))
+            } else {
+                backward.expand(&forward, |s| previous(s, invariant))
+            };
+            if let Some(steps) = meet {
+                return steps;
+            }
+        }
+    }
 
-        for _ in 0..steps {
-            let mut next: Vec<Self> = Vec::new();
-            for state in current {
-                let more = state.next(invariant);
-                for state in more {
-                    if !seen.contains(&state) {
-                        seen.insert(state);
-                        next.push(state);
-                    }
+    /// Least total cost of the weighted transitions from initial to a State where goal is
+    /// true, with the States along the way starting at initial, or None if it can't be reached
+    fn cheapest<G>(initial: Self, goal: G, invariant: &T) -> Option<(usize, Vec<Self>)>
+    where
+        G: Fn(&Self) -> bool,
+    {
+        Self::cheapest_with_heuristic(initial, goal, |_| 0, invariant)
+    }
+
+    /// As cheapest, but exploring first the States where the cost so far plus the heuristic
+    /// is least, the heuristic must never over-estimate the remaining cost to the goal
+    ///
+    /// Panics if the current Budget is exceeded, see Budget::enforce
+    fn cheapest_with_heuristic<G, H>(
+        initial: Self,
+        goal: G,
+        heuristic: H,
+        invariant: &T,
+    ) -> Option<(usize, Vec<Self>)>
+    where
+        G: Fn(&Self) -> bool,
+        H: Fn(&Self) -> usize,
+    {
+        let budget = Budget::current();
+        match Self::cheapest_within(initial, goal, heuristic, &budget, invariant) {
+            Ok(found) => Some(found),
+            Err(Unfound::Exhausted) => None,
+            Err(e) => panic!(
+                "Gave up searching from {}: {e}",
+                initial.describe(invariant)
+            ),
+        }
+    }
+
+    /// As cheapest_with_heuristic, but explaining why no route to the goal was found, either
+    /// because every reachable State was seen or because the Budget was exceeded first
+    ///
+    /// The depth of the Budget limits the transitions along the route to each State explored
+    fn cheapest_within<G, H>(
+        initial: Self,
+        goal: G,
+        heuristic: H,
+        budget: &Budget,
+        invariant: &T,
+    ) -> Result<(usize, Vec<Self>), Unfound>
+    where
+        G: Fn(&Self) -> bool,
+        H: Fn(&Self) -> usize,
+    {
+        // States are numbered in the order seen, the heap holds (estimate, cost, number)
+        let mut states: Vec<Self> = vec![initial];
+        let mut numbers: HashMap<Self, usize> = HashMap::from([(initial, 0)]);
+        let mut costs: Vec<usize> = vec![0];
+        let mut previous: Vec<usize> = vec![0];
+        let mut depths: Vec<usize> = vec![0];
+        let mut todo = BinaryHeap::from([Reverse((heuristic(&initial), 0, 0))]);
+
+        while let Some(Reverse((_, cost, number))) = todo.pop() {
+            if cost > costs[number] {
+                continue;
+            }
+            let state = states[number];
+            if goal(&state) {
+                let mut path = vec![state];
+                let mut n = number;
+                while n != 0 {
+                    n = previous[n];
+                    path.push(states[n]);
                 }
+                path.reverse();
+                return Ok((cost, path));
+            }
+            let depth = depths[number];
+            budget
+                .check(states.len(), depth)
+                .map_err(Unfound::Exceeded)?;
+            for (new, step) in state.next_weighted(invariant) {
+                let cost = cost + step;
+                let n = match numbers.entry(new) {
+                    hash_map::Entry::Occupied(e) => {
+                        let n = *e.get();
+                        if cost >= costs[n] {
+                            continue;
+                        }
+                        costs[n] = cost;
+                        previous[n] = number;
+                        depths[n] = depth + 1;
+                        n
+                    }
+                    hash_map::Entry::Vacant(e) => {
+                        let n = states.len();
+                        e.insert(n);
+                        states.push(new);
+                        costs.push(cost);
+                        previous.push(number);
+                        depths.push(depth + 1);
+                        n
+                    }
+                };
+                todo.push(Reverse((cost + heuristic(&new), cost, n)));
             }
-            current = next;
         }
-        seen.len()
+        Err(Unfound::Exhausted)
     }
 
-    /// Report (if debug is true) the States from the goal back to the initial
-    fn report(initial: Self, goal: Self, invariant: &T, debug: bool) -> Self {
-        if initial == goal {
-            return initial;
-        }
+    /// Possible states after up to steps taken
+    fn count(initial: Self, steps: usize, invariant: &T) -> usize {
+        let expand = |frontier: &[Self]| frontier.iter().map(|s| s.next(invariant)).collect();
+        reachable(initial, steps, expand)
+    }
 
+    /// As count, but with each frontier of the search spread across threads
+    fn count_parallel(initial: Self, steps: usize, invariant: &T) -> usize
+    where
+        Self: Send + Sync,
+        T: Sync,
+    {
+        let expand = |frontier: &[Self]| next_parallel(frontier, invariant);
+        reachable(initial, steps, expand)
+    }
+
+    /// Route with fewest State transitions from initial to a State where goal is true, or
+    /// None if no such State can be reached
+    fn report<G>(initial: Self, goal: G, invariant: &T) -> Option<Route<Self>>
+    where
+        G: Fn(&Self) -> bool,
+    {
         let mut seen: HashMap<Self, Self> = HashMap::new();
         seen.insert(initial, initial);
-        let mut current: Vec<Self> = Vec::with_capacity(1);
-        current.push(initial);
+        let mut current: Vec<Self> = vec![initial];
+        let mut peak = 0;
 
         loop {
+            peak = peak.max(current.len());
+            if let Some(&state) = current.iter().find(|state| goal(state)) {
+                let mut path = vec![state];
+                let mut prev = state;
+                while prev != initial {
+                    prev = seen[&prev];
+                    path.push(prev);
+                }
+                path.reverse();
+                let stats = Stats {
+                    seen: seen.len(),
+                    peak,
+                    depth: path.len() - 1,
+                };
+                return Some(Route { path, stats });
+            }
+            if current.is_empty() {
+                return None;
+            }
             let mut next: Vec<Self> = Vec::new();
             for state in current {
                 let more = state.next(invariant);
@@ -200,83 +826,658 @@ pub trait State<T>: Copy + Eq + Hash {
                         e.insert(state);
                         next.push(new);
                     }
-                    if new == goal {
-                        if debug {
-                            let mut prev = &new;
-                            while prev != &initial {
-                                println!("D: {}", prev.describe(invariant));
-                                prev = seen.get(prev).unwrap();
-                            }
-                        }
-                        return new;
-                    }
                 }
             }
-            if debug {
-                println!("D: {} distinct states seen", seen.len());
-                println!("D: {} new states this iteration", next.len());
-            }
             current = next;
         }
     }
 }
 
+// The States in more which haven't been seen before, in order, which are now seen
+fn novel<S: Copy + Eq + Hash>(more: Vec<Vec<S>>, seen: &mut HashSet<S>) -> Vec<S> {
+    more.into_iter()
+        .flatten()
+        .filter(|&state| seen.insert(state))
+        .collect()
+}
+
+// Breadth first search where expand finds the next States of each State in a frontier
+fn fewest<S, P, E>(
+    initial: S,
+    predicate: P,
+    mut expand: E,
+    budget: &Budget,
+) -> Result<usize, Unfound>
+where
+    S: Copy + Eq + Hash,
+    P: Fn(&S) -> bool,
+    E: FnMut(&[S]) -> Vec<Vec<S>>,
+{
+    if predicate(&initial) {
+        return Ok(0);
+    }
+    let mut seen: HashSet<S> = HashSet::from([initial]);
+    let mut current: Vec<S> = vec![initial];
+    let mut steps = 0;
+    while !current.is_empty() {
+        budget.check(seen.len(), steps).map_err(Unfound::Exceeded)?;
+        steps += 1;
+        current = novel(expand(&current), &mut seen);
+        if current.iter().any(&predicate) {
+            return Ok(steps);
+        }
+    }
+    Err(Unfound::Exhausted)
+}
+
+// How many States are seen by a breadth first search of this many steps
+fn reachable<S, E>(initial: S, steps: usize, mut expand: E) -> usize
+where
+    S: Copy + Eq + Hash,
+    E: FnMut(&[S]) -> Vec<Vec<S>>,
+{
+    let mut seen: HashSet<S> = HashSet::from([initial]);
+    let mut current: Vec<S> = vec![initial];
+    for _ in 0..steps {
+        current = novel(expand(&current), &mut seen);
+    }
+    seen.len()
+}
+
+// Frontiers smaller than this aren't worth the cost of starting threads
+const PARALLEL: usize = 256;
+
+// The next States of each State in the frontier, in order, with the frontier shared out
+// between threads
+fn next_parallel<S, T>(frontier: &[S], invariant: &T) -> Vec<Vec<S>>
+where
+    S: State<T> + Send + Sync,
+    T: Sync,
+{
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    if threads == 1 || frontier.len() < PARALLEL {
+        return frontier.iter().map(|s| s.next(invariant)).collect();
+    }
+    let size = frontier.len().div_ceil(threads);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = frontier
+            .chunks(size)
+            .map(|chunk| {
+                scope.spawn(move || chunk.iter().map(|s| s.next(invariant)).collect::<Vec<_>>())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("Expanding a frontier shouldn't panic"))
+            .collect()
+    })
+}
+
+// One direction of a bidirectional search, each State seen with its distance from the start
+struct Side<S> {
+    seen: HashMap<S, usize>,
+    current: Vec<S>,
+    depth: usize,
+}
+
+impl<S: Copy + Eq + Hash> Side<S> {
+    fn new(start: S) -> Self {
+        Self {
+            seen: HashMap::from([(start, 0)]),
+            current: vec![start],
+            depth: 0,
+        }
+    }
+
+    // Take one more step from the whole frontier, and if this meets the other side, the
+    // fewest steps for the whole route
+    fn expand<M>(&mut self, other: &Self, more: M) -> Option<usize>
+    where
+        M: Fn(&S) -> Vec<S>,
+    {
+        self.depth += 1;
+        let mut next: Vec<S> = Vec::new();
+        let mut meet: Option<usize> = None;
+        for state in self.current.iter() {
+            for new in more(state) {
+                if let hash_map::Entry::Vacant(e) = self.seen.entry(new) {
+                    e.insert(self.depth);
+                    next.push(new);
+                    if let Some(d) = other.seen.get(&new) {
+                        let steps = self.depth + d;
+                        meet = Some(meet.map_or(steps, |m| m.min(steps)));
+                    }
+                }
+            }
+        }
+        self.current = next;
+        meet
+    }
+}
+
+/// Limits on how much work a search may do, by default there are no limits
+///
+/// The limits are checked before each step of the search is taken, so a search may see some
+/// more States than allowed while it takes the last step. Searches which aren't given a
+/// Budget use the current one, see enforce
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Budget {
+    /// Most distinct States to see, including the initial State
+    pub states: Option<usize>,
+    /// Most steps to take from the initial State
+    pub depth: Option<usize>,
+    /// When to give up
+    pub deadline: Option<Instant>,
+}
+
+thread_local! {
+    static BUDGET: Cell<Budget> = Cell::new(Budget::default());
+}
+
+// Puts back the previous Budget when dropped, even while a search is panicking
+struct Restore(Budget);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        BUDGET.with(|budget| budget.set(self.0));
+    }
+}
+
+impl Budget {
+    /// The Budget on this thread for searches which aren't given one, by default no limits
+    pub fn current() -> Self {
+        BUDGET.with(Cell::get)
+    }
+
+    /// Run f with this as the current Budget on this thread, so that any search f makes
+    /// without a Budget of its own gives up, by panicking, once this is exceeded
+    pub fn enforce<R>(self, f: impl FnOnce() -> R) -> R {
+        let _restore = Restore(BUDGET.with(|budget| budget.replace(self)));
+        f()
+    }
+
+    /// Whether another step may be taken having seen this many States in this many steps
+    pub fn check(&self, seen: usize, depth: usize) -> Result<(), Limit> {
+        if self.states.is_some_and(|states| seen > states) {
+            Err(Limit::States)
+        } else if self.depth.is_some_and(|most| depth >= most) {
+            Err(Limit::Depth)
+        } else if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            Err(Limit::Deadline)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Which part of a Budget was exceeded
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Limit {
+    States,
+    Depth,
+    Deadline,
+}
+
+impl std::fmt::Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::States => f.write_str("the most states"),
+            Self::Depth => f.write_str("the most steps"),
+            Self::Deadline => f.write_str("the deadline"),
+        }
+    }
+}
+
+/// Why a search didn't find what it was looking for
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Unfound {
+    /// Every reachable State was seen
+    Exhausted,
+    /// The search gave up when this Limit was exceeded
+    Exceeded(Limit),
+}
+
+impl std::fmt::Display for Unfound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exhausted => f.write_str("every reachable state was seen"),
+            Self::Exceeded(limit) => write!(f, "the search exceeded {limit}"),
+        }
+    }
+}
+
+/// How much work a search did
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Distinct States seen, including the initial State
+    pub seen: usize,
+    /// Most States waiting to be explored at once
+    pub peak: usize,
+    /// Steps taken from the initial State
+    pub depth: usize,
+}
+
+/// The States from initial to goal found by a search, and how much work it did
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route<S> {
+    /// Every State in order, starting with the initial State and ending with the goal
+    pub path: Vec<S>,
+    pub stats: Stats,
+}
+
+impl<S> Route<S> {
+    /// Each State along the path as described by the State
+    pub fn describe<T>(&self, invariant: &T) -> Vec<String>
+    where
+        S: State<T>,
+    {
+        self.path.iter().map(|s| s.describe(invariant)).collect()
+    }
+}
+
+// Brent's algorithm, finding how many steps before the cycle starts and then its period, or
+// else the value reached if the values haven't repeated within the limit of steps
+fn brent<V, F>(initial: V, step: &mut F, limit: Option<usize>) -> Result<(usize, usize), V>
+where
+    V: Clone + PartialEq,
+    F: FnMut(V) -> V,
+{
+    if limit == Some(0) {
+        return Err(initial);
+    }
+    let mut steps = 1;
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial.clone());
+    while tortoise != hare {
+        if limit == Some(steps) {
+            return Err(hare);
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(hare);
+        steps += 1;
+        period += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(tortoise);
+        hare = step(hare);
+        start += 1;
+    }
+    Ok((start, period))
+}
+
+/// Where the values made by repeatedly applying step to initial begin to repeat, as the
+/// number of steps before the cycle starts and then the period of the cycle
+///
+/// Uses Brent's algorithm, so only a few values are kept however long the cycle is, but it
+/// never returns if the values don't repeat
+pub fn cycle<V, F>(initial: V, mut step: F) -> (usize, usize)
+where
+    V: Clone + PartialEq,
+    F: FnMut(V) -> V,
+{
+    match brent(initial, &mut step, None) {
+        Ok(cycle) => cycle,
+        Err(_) => unreachable!("Cycle detection without a limit only stops at a cycle"),
+    }
+}
+
+/// The value after applying step to initial n times, skipping whole cycles once the values
+/// repeat, so that n can be very large
+///
+/// Looking for a cycle stops after n steps, so this returns even if the values never repeat
+pub fn nth<V, F>(initial: V, mut step: F, n: usize) -> V
+where
+    V: Clone + PartialEq,
+    F: FnMut(V) -> V,
+{
+    let (start, period) = match brent(initial.clone(), &mut step, Some(n)) {
+        Ok(cycle) => cycle,
+        Err(value) => return value,
+    };
+    let n = if n < start {
+        n
+    } else {
+        start + (n - start) % period
+    };
+    (0..n).fold(initial, |value, _| step(value))
+}
+
 /// Number of permutations of n things is n!
 pub const fn permutations(n: usize) -> usize {
-    match n {
-        1 => 1,
-        2 => 2,
-        3 => 6,
-        4 => 24,
-        5 => 120,
-        6 => 720,
-        7 => 5040,
-        8 => 40320,
-        9 => 362880,
-        10 => 3628800,
-        _ => {
-            panic!("Unknown permutations");
-        }
+    match combinatorics::factorial(n) {
+        Some(n) => n,
+        None => panic!("Too many permutations"),
     }
 }
 
-/// Heap's Algorithm for permuting slices up to length 10
-/// handle zero specially to do nothing
+/// Heap's Algorithm for permuting slices, step n makes the nth permutation from the one
+/// before, as combinatorics::Heap does, and step zero does nothing
 pub fn heap<T>(a: &mut [T], n: usize) {
-    assert!(a.len() < 11);
     if n == 0 {
-        //return;
-    } else if n % 2 == 1 {
-        a.swap(0, 1);
-    } else if n % 6 > 0 {
-        a.swap(0, 2);
-    } else if n % 24 > 0 {
-        let p = (n / 8) % 3;
-        a.swap(p, 3);
-    } else if n % 120 > 0 {
-        a.swap(0, 4);
-    } else if n % 720 > 0 {
-        let p = (n / 144) % 5;
-        a.swap(p, 5);
-    } else if n % 5040 > 0 {
-        a.swap(0, 6);
-    } else if n % 40320 > 0 {
-        let p = (n / 5760) % 7;
-        a.swap(p, 7);
-    } else if n % 362880 > 0 {
-        a.swap(0, 8);
+        return;
+    }
+    // Position k is swapped at the steps which are multiples of k! but not of (k + 1)!
+    let mut k = 1;
+    let mut fact: usize = 1;
+    while fact.checked_mul(k + 1).is_some_and(|f| n.is_multiple_of(f)) {
+        fact *= k + 1;
+        k += 1;
+    }
+    assert!(k < a.len(), "Step {n} is beyond every permutation");
+    if k.is_multiple_of(2) {
+        a.swap(0, k);
     } else {
-        let p = (n / 403200) % 9;
-        a.swap(p, 9);
+        // Position k swaps with each earlier position in turn
+        let p = (n / fact) % (k + 1) - 1;
+        a.swap(p, k);
     }
 }
 
+pub mod combinatorics;
+pub mod error;
+pub mod json;
 pub mod map;
+pub mod runner;
+pub mod y2023;
 
-#[cfg(test)]
+pub use error::{Error, ParseError};
+
+/// Every year's puzzles, oldest first
+pub const YEARS: [runner::Year; 1] = [y2023::YEAR];
 
+#[cfg(test)]
 mod tests {
-    use crate::heap;
+    use crate::{heap, Budget, Contents, Error, Limit, State, Stats, Unfound};
+    use std::ops::ControlFlow;
+    use std::time::Instant;
+
+    #[test]
+    fn try_numbers() {
+        let ctxt = Contents {
+            text: String::from("12\n-4\nfive\n6\n"),
+        };
+        let numbers: Result<Vec<isize>, Error> = ctxt.try_numbers().collect();
+        match numbers {
+            Err(Error::Parse(e)) => {
+                assert_eq!(e.line, 3);
+                assert_eq!(e.found(), "five");
+            }
+            _ => panic!("Line 3 should not parse"),
+        }
+        let numbers: Vec<isize> = ctxt.numbers().collect();
+        assert_eq!(numbers, [12, -4, 0, 6]);
+    }
+
+    #[test]
+    fn sections() {
+        let ctxt = Contents {
+            text: String::from("\n1\n2\n\n\n3\r\n  \n4\n"),
+        };
+        let sections: Vec<&str> = ctxt.sections().collect();
+        assert_eq!(sections, ["1\n2", "3", "4"]);
+    }
+
+    #[test]
+    fn ints() {
+        let found: Result<Vec<isize>, _> = crate::ints("x=-3, y=14 @ 1-2 -x -").collect();
+        assert_eq!(found.unwrap(), [-3, 14, 1, 2]);
+
+        let ctxt = Contents {
+            text: String::from("seeds: 1\n\n2 3\n4 -5 300\n"),
+        };
+        let section = ctxt.sections().nth(1).unwrap();
+        let found: Result<Vec<u8>, _> = ctxt.ints(section).collect();
+        let e = found.unwrap_err();
+        assert_eq!(e.line, 4);
+        assert_eq!(e.found(), "-5");
+        assert_eq!(e.text, "4 -5 300");
+
+        let seeds = ctxt.lines().next().unwrap().strip_prefix("seeds:").unwrap();
+        let found: Result<Vec<i8>, _> = ctxt.ints(seeds).collect();
+        assert_eq!(found.unwrap(), [1]);
+        let last = ctxt.lines().nth(3).unwrap();
+        let e = ctxt.ints::<u8>(&last[2..]).nth(1).unwrap().unwrap_err();
+        assert_eq!((e.line, e.span.clone(), e.found()), (4, 5..8, "300"));
+    }
+
+    #[test]
+    fn fields() {
+        let found: Result<Vec<i32>, _> = crate::fields("19, 13,  -30", ',').collect();
+        assert_eq!(found.unwrap(), [19, 13, -30]);
+        let found: Result<Vec<u32>, _> = crate::fields(" 7  15   30", ' ').collect();
+        assert_eq!(found.unwrap(), [7, 15, 30]);
+        let found: Result<Vec<u32>, _> = crate::fields("1,2x,3", ',').collect();
+        assert_eq!(
+            found.unwrap_err().to_string(),
+            "Line 1, column 3: expected u32 but found \"2x\""
+        );
+    }
+
+    #[test]
+    fn parse_lines() {
+        let ctxt = Contents {
+            text: String::from("1\nfour\n9\n-\n"),
+        };
+        let Err(errors) = ctxt.parse_lines::<u8>() else {
+            panic!("Lines 2 and 4 should not parse");
+        };
+        let lines: Vec<usize> = errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, [2, 4]);
+        assert_eq!(
+            errors[0].to_string(),
+            "Line 2, column 1: expected u8 but found \"four\""
+        );
+        let ctxt = Contents {
+            text: String::from("1\n2\n"),
+        };
+        assert_eq!(ctxt.parse_lines::<u8>().unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn try_number() {
+        let ctxt = Contents {
+            text: String::from(" 1234\n"),
+        };
+        assert_eq!(ctxt.try_number().unwrap(), 1234);
+        let ctxt = Contents {
+            text: String::from("12a4\n"),
+        };
+        assert!(ctxt.try_number().is_err());
+    }
+
+    #[test]
+    fn misplaced() {
+        let ctxt = Contents {
+            text: String::from("\n1,2,\n3,x,5\n"),
+        };
+        let Some(Err(Error::Parse(e))) = ctxt.try_list_numbers().nth(3) else {
+            panic!("x is not a number");
+        };
+        assert_eq!((e.line, e.span, e.text.as_str()), (3, 2..3, "3,x,5"));
+        let ctxt = Contents {
+            text: String::from("\n45a6\n"),
+        };
+        let Some(Err(Error::Parse(e))) = ctxt.try_digits().nth(2) else {
+            panic!("a is not a digit");
+        };
+        assert_eq!((e.line, e.span.clone(), e.found()), (2, 2..3, "a"));
+    }
+
+    // Add one for a cost of one, or triple for a cost of five, up to a limit
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Count(u32);
+
+    impl State<u32> for Count {
+        fn describe(&self, _limit: &u32) -> String {
+            self.0.to_string()
+        }
+
+        fn next(&self, limit: &u32) -> Vec<Self> {
+            self.next_weighted(limit)
+                .into_iter()
+                .map(|(c, _)| c)
+                .collect()
+        }
+
+        fn next_weighted(&self, limit: &u32) -> Vec<(Self, usize)> {
+            [(Count(self.0 + 1), 1), (Count(self.0 * 3), 5)]
+                .into_iter()
+                .filter(|(c, _)| c.0 <= *limit)
+                .collect()
+        }
+    }
+
+    // The States which have this Count among their next States
+    fn previous(count: &Count, limit: &u32) -> Vec<Count> {
+        let mut previous = Vec::new();
+        if count.0 > 0 && count.0 <= *limit {
+            previous.push(Count(count.0 - 1));
+        }
+        if count.0.is_multiple_of(3) && count.0 <= *limit {
+            previous.push(Count(count.0 / 3));
+        }
+        previous
+    }
+
+    #[test]
+    fn best() {
+        assert_eq!(Count::best(Count(1), Count(10), &20), 3);
+        assert_eq!(
+            Count::best_bidirectional(Count(1), Count(10), previous, &20),
+            3
+        );
+        assert_eq!(
+            Count::best_bidirectional(Count(7), Count(7), previous, &20),
+            0
+        );
+        for goal in [2, 17, 500, 6561, 99_999] {
+            let best = Count::best(Count(1), Count(goal), &100_000);
+            let both = Count::best_bidirectional(Count(1), Count(goal), previous, &100_000);
+            let parallel = Count::steps_parallel(Count(1), |&c| c == Count(goal), &100_000);
+            assert_eq!((both, parallel), (best, best));
+        }
+    }
+
+    #[test]
+    fn budget() {
+        let ten = |c: &Count| c.0 == 10;
+        let unlimited = Budget::default();
+        assert_eq!(Count::steps_within(Count(1), ten, &unlimited, &20), Ok(3));
+        assert_eq!(
+            Count::steps_within(Count(1), |c| c.0 == 0, &unlimited, &20),
+            Err(Unfound::Exhausted)
+        );
+        let shallow = Budget {
+            depth: Some(2),
+            ..Budget::default()
+        };
+        assert_eq!(
+            Count::steps_within(Count(1), ten, &shallow, &20),
+            Err(Unfound::Exceeded(Limit::Depth))
+        );
+        let small = Budget {
+            states: Some(5),
+            ..Budget::default()
+        };
+        assert_eq!(
+            Count::steps_within(Count(1), ten, &small, &20),
+            Err(Unfound::Exceeded(Limit::States))
+        );
+        let late = Budget {
+            deadline: Some(Instant::now()),
+            ..Budget::default()
+        };
+        assert_eq!(
+            Count::steps_within(Count(1), ten, &late, &u32::MAX),
+            Err(Unfound::Exceeded(Limit::Deadline))
+        );
+        let odd = |c: &Count, _: &u32, _| match c.0 % 2 {
+            1 => ControlFlow::Break(c.0),
+            _ => ControlFlow::Continue(()),
+        };
+        assert_eq!(
+            Count::search_within(Count(2), odd, &unlimited, &4),
+            Ok(vec![3])
+        );
+        assert_eq!(
+            Count::search_within(Count(2), odd, &shallow, &u32::MAX),
+            Err(Limit::Depth)
+        );
+    }
+
+    #[test]
+    fn count_parallel() {
+        for steps in [0, 1, 5, 20, 40] {
+            assert_eq!(
+                Count::count_parallel(Count(1), steps, &100_000),
+                Count::count(Count(1), steps, &100_000)
+            );
+        }
+    }
+
+    #[test]
+    fn cheapest() {
+        let route = [1, 2, 3, 9, 10].map(Count);
+        let goal = |c: &Count| c.0 == 10;
+        assert_eq!(
+            Count::cheapest(Count(1), goal, &20),
+            Some((8, route.to_vec()))
+        );
+        let heuristic = |c: &Count| usize::from(c.0 < 10);
+        assert_eq!(
+            Count::cheapest_with_heuristic(Count(1), goal, heuristic, &20),
+            Some((8, route.to_vec()))
+        );
+        assert_eq!(Count::cheapest(Count(1), |c| c.0 == 0, &20), None);
+    }
+
+    #[test]
+    fn report() {
+        let route = Count::report(Count(1), |&c| c == Count(10), &20).expect("10 is reachable");
+        assert_eq!(route.describe(&20), ["1", "3", "9", "10"]);
+        assert_eq!(
+            route.stats,
+            Stats {
+                seen: 11,
+                peak: 5,
+                depth: 3
+            }
+        );
+        let route = Count::report(Count(4), |&c| c == Count(4), &20).expect("Already there");
+        assert_eq!(route.path, [Count(4)]);
+        let route = Count::report(Count(1), |c| c.0 > 12, &20).expect("Past 12 is reachable");
+        assert_eq!(route.describe(&20), ["1", "2", "6", "18"]);
+        assert_eq!(Count::report(Count(1), |&c| c == Count(30), &20), None);
+    }
+
+    #[test]
+    fn cycle() {
+        let double = |n: u32| (n * 2) % 10;
+        assert_eq!(crate::cycle(1, double), (1, 4));
+        assert_eq!(crate::cycle(5, double), (1, 1));
+        assert_eq!(crate::cycle(2, double), (0, 4));
+        assert_eq!(crate::nth(1, double, 0), 1);
+        assert_eq!(crate::nth(1, double, 3), 8);
+        assert_eq!(crate::nth(1, double, 1_000_000_000), 6);
+        assert_eq!(crate::nth(1, |n: u64| n + 1, 0), 1);
+        assert_eq!(crate::nth(1, |n: u64| n + 1, 5), 6);
+    }
 
     #[test]
     fn heap_two() {
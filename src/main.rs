@@ -1,64 +1,229 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
+use sky::runner::{self, Answers, Part, Year};
+use sky::{Input, YEARS};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
 
-use sky::days;
+const USAGE: &str = "Provide a parameter specifying which day e.g. 1a means day 1, part A while 4b means day 4, part B, or all, or a range of days e.g. 1..=10, or bench 17b, or check, or compare, optionally after a year e.g. 2023 5b, with --format json to print a JSON object for each part, and --timeout SECONDS to give up on a search which takes longer";
 
 fn main() {
     let mut args = std::env::args();
     args.next();
 
-    let day = args.next().expect("Provide a parameter specifying which day e.g. 1a means day 1, part A while 4b means day 4, part B").to_ascii_lowercase();
+    let mut positional: Vec<String> = Vec::new();
+    let mut input = Input::from_env();
+    let mut iterations = 10;
+    let mut warmup = 1;
+    let mut answers_path: Option<PathBuf> = None;
+    let mut record = false;
+    let mut solver: Option<String> = None;
+    let mut json = false;
+    let mut timeout: Option<Duration> = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args
+                    .next()
+                    .expect("--input should name a file, or - for stdin");
+                input = Input::path(&path);
+            }
+            "--inputs" => {
+                let dir = args.next().expect("--inputs should name a directory");
+                input = Input::Directory(dir.into());
+            }
+            "--iterations" => {
+                iterations = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .expect("--iterations should be a positive number");
+            }
+            "--warmup" => {
+                warmup = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .expect("--warmup should be a number");
+            }
+            "--answers" => {
+                let path = args.next().expect("--answers should name a file");
+                answers_path = Some(path.into());
+            }
+            "--record" => {
+                record = true;
+            }
+            "--format" => match args.next().as_deref() {
+                Some("json") => json = true,
+                Some("text") => json = false,
+                _ => panic!("--format should be json or text"),
+            },
+            "--timeout" => {
+                let seconds = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n: &f64| n >= 0.0 && n.is_finite())
+                    .expect("--timeout should be a number of seconds");
+                timeout = Some(Duration::from_secs_f64(seconds));
+            }
+            "--solver" => {
+                solver = Some(args.next().expect("--solver should name a solver"));
+            }
+            _ => positional.push(arg.to_ascii_lowercase()),
+        }
+    }
+
+    let mut positional = positional.into_iter().peekable();
+    let year = match positional.next_if(|arg| arg.len() == 4 && arg.parse::<u16>().is_ok()) {
+        Some(arg) => choose(arg.parse().unwrap()),
+        None => YEARS
+            .last()
+            .expect("At least one year should be registered"),
+    };
+    let answers_path = answers_path.unwrap_or_else(|| input.answers(year.year));
+    let input = input.year(year.year);
+    let parts = year.parts;
+    let mode = positional.next().expect(USAGE);
+    // Only bench, check and compare take a further parameter, check them all before running
+    let parameter = positional.next();
+    let takes = matches!(mode.as_str(), "bench" | "check" | "compare");
+    if let Some(arg) = positional
+        .next()
+        .or_else(|| parameter.clone().filter(|_| !takes))
+    {
+        panic!("Unexpected parameter {arg}");
+    }
+    if json && takes {
+        panic!("--format json is only available when solving days, not for {mode}");
+    }
+    match mode.as_str() {
+        "bench" => {
+            let day = parameter.expect(USAGE);
+            let part = select(find(year, &day), solver.as_deref());
+            match runner::bench(&part, &input, warmup, iterations, timeout) {
+                Ok((parse, stats)) => {
+                    println!("{} parse: {parse:.3?}", part.name());
+                    println!("{} solve: {stats}", part.name());
+                }
+                Err(e) => {
+                    eprintln!("{}: {e}", part.day);
+                    std::process::exit(1);
+                }
+            }
+        }
+        "check" => {
+            let days = match parameter {
+                Some(days) => runner::days(&days).expect(USAGE),
+                None => 1..=usize::MAX,
+            };
+            let mut answers = Answers::read(&answers_path).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            one_day(parts, &days, &input);
+            let reports = runner::run_all(parts, days, &input, timeout);
+            if record {
+                answers.record(&reports);
+                if let Err(e) = answers.write(&answers_path) {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            }
+            if !runner::check(&reports, &answers) {
+                std::process::exit(1);
+            }
+        }
+        "compare" => {
+            let days = match parameter {
+                Some(days) => runner::days(&days).expect(USAGE),
+                None => 1..=usize::MAX,
+            };
+            one_day(parts, &days, &input);
+            if !runner::compare(parts, days, &input, timeout) {
+                std::process::exit(1);
+            }
+        }
+        day => {
+            if let Some(days) = runner::days(day) {
+                one_day(parts, &days, &input);
+                let reports = runner::run_all(parts, days, &input, timeout);
+                if json {
+                    for report in reports.iter() {
+                        println!("{}", report.json());
+                    }
+                } else {
+                    runner::table(&reports);
+                }
+            } else {
+                let part = select(find(year, day), solver.as_deref());
+                if json {
+                    let report = part.run(&input, timeout);
+                    println!("{}", report.json());
+                    if !matches!(report.outcome, runner::Outcome::Solved(_)) {
+                        std::process::exit(1);
+                    }
+                } else {
+                    part.solve(&input, timeout);
+                }
+            }
+        }
+    }
+}
+
+/// Choose the Year for a parameter like 2023, or exit if it's not available
+fn choose(year: u16) -> &'static Year {
+    match YEARS.iter().find(|y| y.year == year) {
+        Some(year) => year,
+        None => {
+            println!("{year} not available");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Find the Part for a parameter like 5b, or exit if it's not available
+fn find(year: &Year, day: &str) -> &'static Part {
     let day = format!("day{:0>3}", day);
+    match year.find(&day) {
+        Some(part) => part,
+        None => {
+            println!("{} {day} not available yet", year.year);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Exit unless the chosen days are just one day, where a single file or stdin was given as the
+/// input, since it can't be the input for several days
+fn one_day(parts: &[Part], days: &RangeInclusive<usize>, input: &Input) {
+    if !input.is_single() {
+        return;
+    }
+    let mut chosen = parts.iter().map(Part::number).filter(|n| days.contains(n));
+    let Some(first) = chosen.next() else {
+        return;
+    };
+    if chosen.any(|n| n != first) {
+        eprintln!(
+            "--input is the input for one day, so choose one day e.g. {first}..={first}, or use --inputs to name a directory of inputs"
+        );
+        std::process::exit(1);
+    }
+}
 
-    days!(
-        day.as_str(),
-        day01,
-        day02,
-        day03,
-        day04,
-        day05,
-        day06,
-        day07,
-        day08,
-        day09,
-        day10,
-        day11,
-        day12,
-        day13,
-        day14,
-        day15,
-        day16,
-        day17,
-        day18,
-        day19,
-        day20,
-        day21,
-        day22,
-        day23,
-        day24,
-        day25,
-    );
+/// Choose the named solver for a Part, or exit if there's no solver with that name
+fn select(part: &Part, solver: Option<&str>) -> Part {
+    let Some(solver) = solver else {
+        return *part;
+    };
+    match part.with_solver(solver) {
+        Some(part) => part,
+        None => {
+            let names: Vec<&str> = part.solvers().map(|(name, _)| name).collect();
+            println!(
+                "{} has no {solver} solver, try one of: {}",
+                part.name(),
+                names.join(", ")
+            );
+            std::process::exit(1);
+        }
+    }
 }
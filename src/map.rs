@@ -33,8 +33,10 @@ impl Plane {
         } else {
             self.start - GROWTH
         };
-        let size = if self.size > self.end - offset + GROWTH {
-            self.size
+        // Moving the offset down mustn't move the far end down with it
+        let kept = self.size + (self.offset - offset);
+        let size = if kept > self.end - offset + GROWTH {
+            kept
         } else {
             self.end - offset + GROWTH // When actually growing this ends up adding GROWTH at both edges
         };
@@ -47,6 +49,21 @@ impl Plane {
     }
 }
 
+/// Offsets of the four orthogonal neighbours, north, east, south then west
+pub const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all eight neighbours, clockwise from north west
+pub const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
 #[derive(Clone)]
 pub struct Map<T: Copy + Default> {
     data: Vec<T>,
@@ -182,6 +199,106 @@ impl<T: Copy + Default> Map<T> {
         }
     }
 
+    /// Whether (x, y) is within the x() and y() ranges of the Map
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        self.x().contains(&x) && self.y().contains(&y)
+    }
+
+    /// The (x, y) position and value of each neighbour of (x, y) at these offsets, skipping
+    /// any outside the x() and y() ranges of the Map
+    pub fn neighbours<'a>(
+        &'a self,
+        x: isize,
+        y: isize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (isize, isize, T)> + 'a {
+        offsets
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| self.contains(x, y))
+            .map(|(x, y)| (x, y, self.data[self.position(x, y)]))
+    }
+
+    /// The (x, y) position and value of the orthogonal neighbours of (x, y)
+    pub fn neighbours4(&self, x: isize, y: isize) -> impl Iterator<Item = (isize, isize, T)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS4)
+    }
+
+    /// The (x, y) position and value of all the neighbours of (x, y), including diagonals
+    pub fn neighbours8(&self, x: isize, y: isize) -> impl Iterator<Item = (isize, isize, T)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS8)
+    }
+
+    /// Each value along row y from left to right, or nothing if y is outside y()
+    pub fn row(&self, y: isize) -> impl Iterator<Item = T> + '_ {
+        let inside = self.y().contains(&y);
+        self.x()
+            .filter(move |_| inside)
+            .map(move |x| self.data[self.position(x, y)])
+    }
+
+    /// Each value down column x from top to bottom, or nothing if x is outside x()
+    pub fn column(&self, x: isize) -> impl Iterator<Item = T> + '_ {
+        let inside = self.x().contains(&x);
+        self.y()
+            .filter(move |_| inside)
+            .map(move |y| self.data[self.position(x, y)])
+    }
+
+    // A new Map from (0, 0) to (width - 1, height - 1) where each position is given the value
+    // from the position in this Map chosen by from, given offsets from the top left
+    fn remap<F>(&self, width: isize, height: isize, from: F) -> Self
+    where
+        F: Fn(isize, isize) -> (isize, isize),
+    {
+        let mut map = Self::rect((0, 0), (width - 1, height - 1));
+        let (&left, &top) = (self.x().start(), self.y().start());
+        for y in 0..height {
+            for x in 0..width {
+                let (fx, fy) = from(x, y);
+                map.write(x, y, self.data[self.position(left + fx, top + fy)]);
+            }
+        }
+        map
+    }
+
+    fn width(&self) -> isize {
+        self.x.end - self.x.start + 1
+    }
+
+    fn height(&self) -> isize {
+        self.y.end - self.y.start + 1
+    }
+
+    /// A copy of the Map with rows and columns swapped, so that its top left is (0, 0)
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height(), self.width(), |x, y| (y, x))
+    }
+
+    /// A copy of the Map turned a quarter clockwise, so that its top left is (0, 0)
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height();
+        self.remap(height, self.width(), |x, y| (y, height - 1 - x))
+    }
+
+    /// A copy of the Map turned a quarter anticlockwise, so that its top left is (0, 0)
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width();
+        self.remap(self.height(), width, |x, y| (width - 1 - y, x))
+    }
+
+    /// A copy of the Map mirrored left to right, so that its top left is (0, 0)
+    pub fn flip_h(&self) -> Self {
+        let width = self.width();
+        self.remap(width, self.height(), |x, y| (width - 1 - x, y))
+    }
+
+    /// A copy of the Map mirrored top to bottom, so that its top left is (0, 0)
+    pub fn flip_v(&self) -> Self {
+        let height = self.height();
+        self.remap(self.width(), height, |x, y| (x, height - 1 - y))
+    }
+
     /// Count how many of the mapped positions match the predicate
     pub fn count<P>(&self, predicate: P) -> usize
     where
@@ -207,6 +324,344 @@ impl<T: Copy + Default> Map<T> {
     }
 }
 
+/// Which positions of a Map share a connected region, from Map::components
+#[derive(Clone, Debug)]
+pub struct Components {
+    /// The id of the region at each position, counting from 0 in the order found reading the
+    /// Map row by row, or None where it isn't passable
+    pub labels: Map<Option<usize>>,
+    /// How many positions are in each region, by id
+    pub sizes: Vec<usize>,
+}
+
+/// Whether a position of a Map is part of a boundary, or is enclosed by it, from
+/// Map::enclosed
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// Connected to the edge of the Map without crossing the boundary
+    #[default]
+    Outside,
+    Enclosed,
+    Boundary,
+}
+
+impl<T: Copy + Default> Map<T> {
+    // Label every position connected to (x, y) through passable values, which aren't
+    // labelled yet, with id, returning them
+    fn fill<P>(
+        &self,
+        (x, y): (isize, isize),
+        passable: &P,
+        labels: &mut Map<Option<usize>>,
+        id: usize,
+    ) -> Vec<(isize, isize)>
+    where
+        P: Fn(T) -> bool,
+    {
+        let mut found = Vec::new();
+        if !self.contains(x, y) || !passable(self.data[self.position(x, y)]) {
+            return found;
+        }
+        labels.write(x, y, Some(id));
+        let mut todo = vec![(x, y)];
+        while let Some((x, y)) = todo.pop() {
+            found.push((x, y));
+            for (x, y, value) in self.neighbours4(x, y) {
+                if passable(value) && labels.read(x, y).flatten().is_none() {
+                    labels.write(x, y, Some(id));
+                    todo.push((x, y));
+                }
+            }
+        }
+        found
+    }
+
+    /// Every position reachable from (x, y) by orthogonal moves through passable values,
+    /// never leaving the x() and y() ranges, or nothing if (x, y) itself isn't passable
+    pub fn flood<P>(&self, x: isize, y: isize, passable: P) -> Vec<(isize, isize)>
+    where
+        P: Fn(T) -> bool,
+    {
+        let mut seen = Map::ranged(self.x(), self.y());
+        self.fill((x, y), &passable, &mut seen, 0)
+    }
+
+    /// Label each region of passable values connected by orthogonal moves
+    pub fn components<P>(&self, passable: P) -> Components
+    where
+        P: Fn(T) -> bool,
+    {
+        let mut labels = Map::ranged(self.x(), self.y());
+        let mut sizes = Vec::new();
+        for y in self.y() {
+            for x in self.x() {
+                if labels.read(x, y).flatten().is_some() {
+                    continue;
+                }
+                let found = self.fill((x, y), &passable, &mut labels, sizes.len());
+                if found.is_empty() {
+                    labels.write(x, y, None);
+                } else {
+                    sizes.push(found.len());
+                }
+            }
+        }
+        Components { labels, sizes }
+    }
+
+    /// Classify each position as part of the boundary, or else whether it is enclosed by the
+    /// boundary or connected to the edge of the Map by orthogonal moves
+    pub fn enclosed<B>(&self, boundary: B) -> Map<Region>
+    where
+        B: Fn(T) -> bool,
+    {
+        let Components { labels, sizes } = self.components(|value| !boundary(value));
+        let (&left, &right) = (self.x().start(), self.x().end());
+        let (&top, &bottom) = (self.y().start(), self.y().end());
+        let mut outside = vec![false; sizes.len()];
+        for y in self.y() {
+            for x in self.x() {
+                let edge = x == left || x == right || y == top || y == bottom;
+                if let (true, Some(Some(id))) = (edge, labels.read(x, y)) {
+                    outside[id] = true;
+                }
+            }
+        }
+        let mut regions = Map::ranged(self.x(), self.y());
+        for y in self.y() {
+            for x in self.x() {
+                let region = match labels.read(x, y).flatten() {
+                    Some(id) if outside[id] => Region::Outside,
+                    Some(_) => Region::Enclosed,
+                    None => Region::Boundary,
+                };
+                regions.write(x, y, region);
+            }
+        }
+        regions
+    }
+}
+
+use std::collections::VecDeque;
+
+impl<T: Copy + Default> Map<T> {
+    /// Fewest orthogonal moves through passable values from start to each position, within
+    /// the x() and y() ranges, or None if it can't be reached
+    pub fn distances<P>(&self, (x, y): (isize, isize), passable: P) -> Map<Option<u32>>
+    where
+        P: Fn(T) -> bool,
+    {
+        let mut distances = Map::ranged(self.x(), self.y());
+        for y in self.y() {
+            for x in self.x() {
+                distances.write(x, y, None);
+            }
+        }
+        if self.contains(x, y) && passable(self.data[self.position(x, y)]) {
+            let neighbours = |x, y| self.neighbours4(x, y);
+            breadth(&mut distances, (x, y), neighbours, passable, u32::MAX);
+        }
+        distances
+    }
+}
+
+// Breadth first search from start, which should be passable, recording the distance to each
+// position no further than limit, where neighbours finds the positions next to a position
+fn breadth<T, N, I, P>(
+    distances: &mut Map<Option<u32>>,
+    (x, y): (isize, isize),
+    neighbours: N,
+    passable: P,
+    limit: u32,
+) where
+    N: Fn(isize, isize) -> I,
+    I: Iterator<Item = (isize, isize, T)>,
+    P: Fn(T) -> bool,
+{
+    distances.write(x, y, Some(0));
+    let mut todo = VecDeque::from([(x, y, 0)]);
+    while let Some((x, y, d)) = todo.pop_front() {
+        if d == limit {
+            continue;
+        }
+        for (x, y, value) in neighbours(x, y) {
+            if passable(value) && distances.read(x, y).flatten().is_none() {
+                distances.write(x, y, Some(d + 1));
+                todo.push_back((x, y, d + 1));
+            }
+        }
+    }
+}
+
+/// A view of a Map repeated infinitely in every direction, from Map::tiled
+#[derive(Copy, Clone, Debug)]
+pub struct Tiled<'a, T: Copy + Default> {
+    map: &'a Map<T>,
+}
+
+impl<T: Copy + Default> Map<T> {
+    /// A view of this Map's x() and y() area repeated infinitely in every direction
+    pub fn tiled(&self) -> Tiled<'_, T> {
+        Tiled { map: self }
+    }
+}
+
+impl<T: Copy + Default> Tiled<'_, T> {
+    /// Which copy of the Map (x, y) is in, the Map itself is tile (0, 0) and the tile to its
+    /// right is (1, 0)
+    pub fn tile(&self, x: isize, y: isize) -> (isize, isize) {
+        let (&left, &top) = (self.map.x().start(), self.map.y().start());
+        (
+            (x - left).div_euclid(self.map.width()),
+            (y - top).div_euclid(self.map.height()),
+        )
+    }
+
+    /// The position in the Map itself which (x, y) is a copy of
+    pub fn base(&self, x: isize, y: isize) -> (isize, isize) {
+        let (&left, &top) = (self.map.x().start(), self.map.y().start());
+        (
+            left + (x - left).rem_euclid(self.map.width()),
+            top + (y - top).rem_euclid(self.map.height()),
+        )
+    }
+
+    /// The value at any (x, y), which is the value at its base position in the Map
+    pub fn read(&self, x: isize, y: isize) -> T {
+        let (x, y) = self.base(x, y);
+        self.map.data[self.map.position(x, y)]
+    }
+
+    /// The (x, y) position and value of each neighbour of (x, y) at these offsets
+    pub fn neighbours<'a>(
+        &'a self,
+        x: isize,
+        y: isize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (isize, isize, T)> + 'a {
+        offsets
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .map(|(x, y)| (x, y, self.read(x, y)))
+    }
+
+    /// The (x, y) position and value of the orthogonal neighbours of (x, y)
+    pub fn neighbours4(&self, x: isize, y: isize) -> impl Iterator<Item = (isize, isize, T)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS4)
+    }
+
+    /// The (x, y) position and value of all the neighbours of (x, y), including diagonals
+    pub fn neighbours8(&self, x: isize, y: isize) -> impl Iterator<Item = (isize, isize, T)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS8)
+    }
+
+    /// Fewest orthogonal moves through passable values from start to each position no more
+    /// than limit moves away, as a Map which covers every position reached
+    pub fn distances<P>(&self, (x, y): (isize, isize), passable: P, limit: u32) -> Map<Option<u32>>
+    where
+        P: Fn(T) -> bool,
+    {
+        let mut distances = Map::rect((x, y), (x, y));
+        distances.write(x, y, None);
+        if passable(self.read(x, y)) {
+            let neighbours = |x, y| self.neighbours4(x, y);
+            breadth(&mut distances, (x, y), neighbours, passable, limit);
+        }
+        distances
+    }
+}
+
+impl Map<Option<u32>> {
+    // Positions whose distance satisfies the predicate
+    fn distant<P>(&self, predicate: P) -> Vec<(isize, isize)>
+    where
+        P: Fn(u32) -> bool,
+    {
+        let mut found = Vec::new();
+        for y in self.y() {
+            for (x, distance) in self.x().zip(self.row(y)) {
+                if distance.is_some_and(&predicate) {
+                    found.push((x, y));
+                }
+            }
+        }
+        found
+    }
+
+    /// Positions in a Map from distances which could be reached in exactly n moves, those
+    /// no further than n where the moves left over can be spent stepping back and forth
+    pub fn exactly(&self, n: u32) -> Vec<(isize, isize)> {
+        self.distant(|d| d <= n && (n - d).is_multiple_of(2))
+    }
+
+    /// Positions in a Map from distances which could be reached in n moves or fewer
+    pub fn within(&self, n: u32) -> Vec<(isize, isize)> {
+        self.distant(|d| d <= n)
+    }
+}
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+// Fingerprint of one position and its value, those of every position are summed
+fn print<T: Hash>(x: isize, y: isize, value: T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    (x, y, value).hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<T: Copy + Default + Hash + PartialEq> Map<T> {
+    /// A fingerprint of the values in the Map, which is the same for equal Maps and very
+    /// likely to differ otherwise, positions with the default value don't affect it
+    pub fn fingerprint(&self) -> u64 {
+        let mut sum: u64 = 0;
+        for y in self.y() {
+            for (x, value) in self.x().zip(self.row(y)) {
+                if value != T::default() {
+                    sum = sum.wrapping_add(print(x, y, value));
+                }
+            }
+        }
+        sum
+    }
+
+    /// Write to (x, y) like write, also updating a fingerprint of this Map to match, which
+    /// is far cheaper than calculating the fingerprint again
+    pub fn write_tracking(&mut self, x: isize, y: isize, value: T, fingerprint: &mut u64) {
+        let old = self.read(x, y).unwrap_or_default();
+        if old != T::default() {
+            *fingerprint = fingerprint.wrapping_sub(print(x, y, old));
+        }
+        if value != T::default() {
+            *fingerprint = fingerprint.wrapping_add(print(x, y, value));
+        }
+        self.write(x, y, value);
+    }
+}
+
+/// Maps are equal if they have the same x() and y() ranges with the same values, no matter
+/// how much space they have to grow
+impl<T: Copy + Default + PartialEq> PartialEq for Map<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x() == other.x()
+            && self.y() == other.y()
+            && self.y().all(|y| self.row(y).eq(other.row(y)))
+    }
+}
+
+impl<T: Copy + Default + Eq> Eq for Map<T> {}
+
+impl<T: Copy + Default + Hash> Hash for Map<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x().hash(state);
+        self.y().hash(state);
+        for y in self.y() {
+            for value in self.row(y) {
+                value.hash(state);
+            }
+        }
+    }
+}
+
 use std::fmt;
 impl<T: fmt::Debug + Copy + Default> fmt::Debug for Map<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -264,20 +719,24 @@ impl<T: fmt::Display + Copy + Default> fmt::Display for Map<T> {
     }
 }
 
-use std::convert::Infallible;
+use crate::ParseError;
 use std::str::FromStr;
 impl<T> FromStr for Map<T>
 where
-    char: Into<T>,
-    T: Copy + Default,
+    T: TryFrom<char> + Copy + Default,
+    T::Error: Into<ParseError>,
 {
-    type Err = Infallible;
+    type Err = ParseError;
 
+    /// Each character converted to a T, or where the first character which isn't a T is
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut map = Self::rect((0, 0), (0, 0));
         for (row, line) in s.lines().enumerate() {
-            for (col, ch) in line.chars().enumerate() {
-                let item = ch.into();
+            for (col, (n, ch)) in line.char_indices().enumerate() {
+                let item = T::try_from(ch).map_err(|e| {
+                    let found = &line[n..n + ch.len_utf8()];
+                    e.into().within(line, found).line(row + 1)
+                })?;
                 map.write(col as isize, row as isize, item);
             }
         }
@@ -286,11 +745,10 @@ where
 }
 
 #[cfg(test)]
-
 mod tests {
-    use crate::map::Map;
+    use crate::map::{Map, Region, NEIGHBOURS4};
 
-    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
     enum Maze {
         #[default]
         Wall,
@@ -323,6 +781,17 @@ mod tests {
         assert_eq!(map.y(), 0..=6);
     }
 
+    #[test]
+    fn ranged_write() {
+        let mut map: Map<u8> = Map::ranged(0..=130, 0..=130);
+        map.write(0, 0, 1);
+        map.write(130, 0, 2);
+        map.write(130, 130, 3);
+        assert_eq!(map.read(0, 0), Some(1));
+        assert_eq!(map.read(130, 0), Some(2));
+        assert_eq!(map.read(130, 130), Some(3));
+    }
+
     #[test]
     fn maze_write() {
         let mut map: Map<Maze> = MAZE.parse().unwrap();
@@ -330,4 +799,144 @@ mod tests {
         map.write(1, 1, Maze::Us);
         assert_eq!(map.count(|&m| m == &Maze::Space), 22);
     }
+
+    #[test]
+    fn neighbours() {
+        let map: Map<Maze> = MAZE.parse().unwrap();
+        let around: Vec<(isize, isize, Maze)> = map.neighbours4(1, 1).collect();
+        assert_eq!(
+            around,
+            [
+                (1, 0, Maze::Wall),
+                (2, 1, Maze::Space),
+                (1, 2, Maze::Space),
+                (0, 1, Maze::Wall)
+            ]
+        );
+        assert_eq!(map.neighbours8(0, 0).count(), 3);
+        assert_eq!(map.neighbours8(4, 3).count(), 8);
+        let knight = [(1, 2), (2, 1), (-1, -2)];
+        let jumps: Vec<(isize, isize)> = map
+            .neighbours(7, 4, &knight)
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert_eq!(jumps, [(8, 6), (6, 2)]);
+    }
+
+    #[test]
+    fn orientation() {
+        let mut map: Map<char> = "abc\ndef".parse().unwrap();
+        assert_eq!(map.row(1).collect::<String>(), "def");
+        assert_eq!(map.column(2).collect::<String>(), "cf");
+        assert_eq!(map.row(2).count(), 0);
+        assert_eq!(map.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(map.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(map.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(map.flip_h().to_string(), "cba\nfed\n");
+        assert_eq!(map.flip_v().to_string(), "def\nabc\n");
+
+        map.write(-1, -1, 'z');
+        let turned = map.rotate_cw();
+        assert_eq!((turned.x(), turned.y()), (0..=2, 0..=3));
+        assert_eq!(turned.row(0).collect::<String>(), "\0\0z");
+    }
+
+    #[test]
+    fn equality() {
+        use std::collections::HashSet;
+
+        let maze: Map<Maze> = MAZE.parse().unwrap();
+        let mut roomy: Map<Maze> = Map::rect((-20, -20), (20, 20));
+        let mut fingerprint = roomy.fingerprint();
+        for y in maze.y() {
+            for (x, m) in maze.x().zip(maze.row(y)) {
+                roomy.write_tracking(x, y, m, &mut fingerprint);
+            }
+        }
+        assert_eq!(roomy, maze);
+        assert_eq!(fingerprint, maze.fingerprint());
+        assert_eq!(fingerprint, roomy.fingerprint());
+
+        let mut seen = HashSet::from([maze.clone()]);
+        assert!(!seen.insert(roomy.clone()));
+        roomy.write_tracking(1, 1, Maze::Us, &mut fingerprint);
+        assert_ne!(roomy, maze);
+        assert_ne!(fingerprint, maze.fingerprint());
+        assert_eq!(fingerprint, roomy.fingerprint());
+        assert!(seen.insert(roomy));
+    }
+
+    #[test]
+    fn regions() {
+        let map: Map<char> = "....\n.##.\n.#.#\n.##.\n".parse().unwrap();
+        let open = |c| c == '.';
+        assert_eq!(map.flood(0, 0, open).len(), 8);
+        assert_eq!(map.flood(1, 1, open), []);
+
+        let components = map.components(open);
+        assert_eq!(components.sizes, [8, 1, 1]);
+        assert_eq!(components.labels.read(3, 1), Some(Some(0)));
+        assert_eq!(components.labels.read(2, 2), Some(Some(1)));
+        assert_eq!(components.labels.read(2, 1), Some(None));
+
+        let regions = map.enclosed(|c| c == '#');
+        assert_eq!((regions.x(), regions.y()), (map.x(), map.y()));
+        assert_eq!(regions.read(2, 2), Some(Region::Enclosed));
+        assert_eq!(regions.read(3, 3), Some(Region::Outside));
+        assert_eq!(regions.read(1, 1), Some(Region::Boundary));
+        let outside = |y| regions.row(y).filter(|&r| r == Region::Outside).count();
+        assert_eq!(regions.y().map(outside).sum::<usize>(), 9);
+    }
+
+    #[test]
+    fn distances() {
+        let map: Map<Maze> = MAZE.parse().unwrap();
+        let distances = map.distances((1, 1), |m| m == Maze::Space);
+        assert_eq!((distances.x(), distances.y()), (map.x(), map.y()));
+        assert_eq!(distances.read(1, 1), Some(Some(0)));
+        assert_eq!(distances.read(3, 1), Some(Some(2)));
+        assert_eq!(distances.read(0, 0), Some(None));
+        assert_eq!(
+            distances.within(2),
+            [(1, 1), (2, 1), (3, 1), (1, 2), (1, 3)]
+        );
+        assert_eq!(distances.exactly(2), [(1, 1), (3, 1), (1, 3)]);
+        assert_eq!(distances.within(100).len(), 23);
+        let walls = map.distances((0, 0), |m| m == Maze::Space);
+        assert!(walls.within(100).is_empty());
+    }
+
+    #[test]
+    fn tiled() {
+        let map: Map<char> = "ab\ncd\nef".parse().unwrap();
+        let tiled = map.tiled();
+        assert_eq!(tiled.read(1, 1), 'd');
+        assert_eq!(tiled.read(-1, -1), 'f');
+        assert_eq!(tiled.read(4, 7), 'c');
+        assert_eq!(tiled.tile(4, 7), (2, 2));
+        assert_eq!(tiled.tile(-1, 0), (-1, 0));
+        assert_eq!(tiled.base(-3, -4), (1, 2));
+        let around: String = tiled
+            .neighbours(0, 0, &NEIGHBOURS4)
+            .map(|(_, _, c)| c)
+            .collect();
+        assert_eq!(around, "ebcb");
+
+        let maze: Map<Maze> = MAZE.parse().unwrap();
+        let open = |m| m == Maze::Space;
+        // The maze is walled in, so its copies are out of reach
+        let distances = maze.tiled().distances((1, 1), open, 1000);
+        assert_eq!((distances.x(), distances.y()), (1..=7, 1..=5));
+        assert_eq!(
+            distances.within(1000),
+            maze.distances((1, 1), open).within(1000)
+        );
+        assert_eq!(distances.within(3).len(), 7);
+
+        let plain: Map<Maze> = " ".parse().unwrap();
+        let distances = plain.tiled().distances((0, 0), open, 3);
+        assert_eq!((distances.x(), distances.y()), (-3..=3, -3..=3));
+        assert_eq!(distances.exactly(3).len(), 16);
+        assert_eq!(distances.within(3).len(), 25);
+    }
 }
@@ -0,0 +1,641 @@
+use crate::json::Json;
+use crate::{Budget, Contents, Error, Input, ParseError, Solver};
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::panic;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Parses the input and solves a part that many times, timing both
+pub type Solve = fn(&Contents, usize) -> Result<Timed, ParseError>;
+
+/// One part of one day's puzzle, as registered by the days! macro
+#[derive(Copy, Clone)]
+pub struct Part {
+    /// Name of the day's module, e.g. "day05"
+    pub day: &'static str,
+    /// Either 'a' or 'b'
+    pub part: char,
+    /// Parses the input and then solves it, the given number of times but at least once
+    pub solver: Solve,
+    /// Alternative solvers which should give the same answer
+    pub variants: &'static [Variant],
+    /// Solver for the puzzle's example instead, where the example differs from real input
+    pub example: Option<Solve>,
+}
+
+/// Every Part of one year's puzzles, as registered by the days! macro
+#[derive(Copy, Clone)]
+pub struct Year {
+    pub year: u16,
+    pub parts: &'static [Part],
+}
+
+impl Year {
+    /// Find the Part named e.g. "day05b"
+    pub fn find(&self, name: &str) -> Option<&'static Part> {
+        self.parts.iter().find(|part| part.name() == name)
+    }
+}
+
+/// An alternative named solver for a Part
+#[derive(Copy, Clone)]
+pub struct Variant {
+    pub name: &'static str,
+    pub solver: Solve,
+}
+
+/// The answer from a solver, with how long it took to parse the input once, and how long
+/// each of its solves took
+#[derive(Clone, Debug)]
+pub struct Timed {
+    pub answer: String,
+    pub parse: Duration,
+    pub solves: Vec<Duration>,
+}
+
+/// Parse the input, then use the parsed input to solve the puzzle the given number of times
+/// but at least once, timing the parse and each solve separately, or if the input is
+/// malformed, the ParseError without solving anything
+pub fn timed<'c, T, I, S>(
+    ctxt: &'c Contents,
+    parse: impl Fn(&'c Contents) -> Result<T, ParseError>,
+    solver: S,
+    times: usize,
+) -> Result<Timed, ParseError>
+where
+    T: Borrow<I>,
+    I: ?Sized,
+    S: Solver<I>,
+{
+    let start = Instant::now();
+    let input = parse(ctxt)?;
+    let parse = start.elapsed();
+    let mut answer = String::new();
+    let mut solves = Vec::with_capacity(times.max(1));
+    for _ in 0..times.max(1) {
+        let start = Instant::now();
+        let solved = solver.solve(input.borrow());
+        solves.push(start.elapsed());
+        answer = solved.to_string();
+    }
+    Ok(Timed {
+        answer,
+        parse,
+        solves,
+    })
+}
+
+/// Name of the solver given by the days! macro for a Part, rather than one of its variants
+pub const DEFAULT: &str = "default";
+
+impl Part {
+    /// Name of this part, e.g. "day05b"
+    pub fn name(&self) -> String {
+        format!("{}{}", self.day, self.part)
+    }
+
+    /// Number of the day, e.g. 5
+    pub fn number(&self) -> usize {
+        self.day
+            .trim_start_matches("day")
+            .parse()
+            .expect("Days should be named with a number")
+    }
+
+    /// Name of every solver for this part, including the default, with the solver
+    pub fn solvers(&self) -> impl Iterator<Item = (&'static str, Solve)> {
+        let variants = self.variants.iter().map(|v| (v.name, v.solver));
+        std::iter::once((DEFAULT, self.solver)).chain(variants)
+    }
+
+    /// This part but using the named solver instead, if there is a solver with that name
+    pub fn with_solver(&self, name: &str) -> Option<Self> {
+        let (_, solver) = self.solvers().find(|&(n, _)| n == name)?;
+        Some(Self { solver, ..*self })
+    }
+
+    /// Solve this part from the chosen input and print the answer, or if the input can't be
+    /// read or is malformed, explain why and exit, while a search which exceeds the timeout
+    /// panics
+    pub fn solve(&self, input: &Input, timeout: Option<Duration>) {
+        let ctxt = match input.try_contents(&self.name()) {
+            Ok(ctxt) => ctxt,
+            Err(e) => {
+                eprintln!("{}: {e}", self.day);
+                std::process::exit(1);
+            }
+        };
+        match deadline(timeout).enforce(|| (self.solver)(&ctxt, 1)) {
+            Ok(timed) => println!("{}", timed.answer),
+            Err(e) => {
+                eprintln!("{}: {}", self.day, e.excerpt());
+                std::process::exit(1);
+            }
+        }
+    }
+
+    /// Solve this part from the chosen input, timing it, and catching any panic as a failure,
+    /// including a search which gives up after the timeout, see Budget::enforce
+    pub fn run(&self, input: &Input, timeout: Option<Duration>) -> Report {
+        match input.try_contents(&self.name()) {
+            Ok(ctxt) => self.run_with(&ctxt, timeout),
+            Err(e) => Report {
+                part: *self,
+                outcome: Outcome::Unreadable(e),
+                parse: None,
+                elapsed: Duration::ZERO,
+            },
+        }
+    }
+
+    /// As run, but from input which has already been read, e.g. so that several solvers can
+    /// share it
+    pub fn run_with(&self, ctxt: &Contents, timeout: Option<Duration>) -> Report {
+        let start = Instant::now();
+        let budget = deadline(timeout);
+        let mut parse = None;
+        let outcome = match panic::catch_unwind(|| budget.enforce(|| (self.solver)(ctxt, 1))) {
+            Ok(Ok(timed)) => {
+                parse = Some(timed.parse);
+                Outcome::Solved(timed.answer)
+            }
+            Ok(Err(e)) => Outcome::Malformed(e),
+            Err(payload) => {
+                if let Some(s) = payload.downcast_ref::<&str>() {
+                    Outcome::Panicked(String::from(*s))
+                } else if let Some(s) = payload.downcast_ref::<String>() {
+                    Outcome::Panicked(s.clone())
+                } else {
+                    Outcome::Panicked(String::from("panicked"))
+                }
+            }
+        };
+        Report {
+            part: *self,
+            outcome,
+            parse,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+// A Budget with a deadline after the timeout from now, or no limits without a timeout
+fn deadline(timeout: Option<Duration>) -> Budget {
+    Budget {
+        deadline: timeout.map(|timeout| Instant::now() + timeout),
+        ..Budget::default()
+    }
+}
+
+/// What happened when a Part was run
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(String),
+    Panicked(String),
+    Unreadable(Error),
+    /// The solver's parser rejected the input
+    Malformed(ParseError),
+}
+
+/// The Outcome of running a Part and how long it took
+pub struct Report {
+    pub part: Part,
+    pub outcome: Outcome,
+    /// Time taken to parse the input, if the Part was solved
+    pub parse: Option<Duration>,
+    /// Time taken by the solver, including parsing the input
+    pub elapsed: Duration,
+}
+
+impl Report {
+    /// This Report as a JSON object, with times in seconds and status "ok", "panicked",
+    /// "unreadable" or "malformed"
+    ///
+    /// The parse time is null unless the Part was solved, and then the solve time excludes it
+    pub fn json(&self) -> Json {
+        let (status, answer, error) = match &self.outcome {
+            Outcome::Solved(answer) => ("ok", Some(answer.clone()), None),
+            Outcome::Panicked(message) => ("panicked", None, Some(message.clone())),
+            Outcome::Unreadable(e) => ("unreadable", None, Some(e.to_string())),
+            Outcome::Malformed(e) => ("malformed", None, Some(e.to_string())),
+        };
+        let solve = self.elapsed - self.parse.unwrap_or_default();
+        Json::object([
+            ("day", Json::from(self.part.number())),
+            ("part", Json::from(self.part.part.to_string())),
+            ("answer", Json::from(answer)),
+            ("parse", Json::from(self.parse.map(|t| t.as_secs_f64()))),
+            ("solve", Json::from(solve.as_secs_f64())),
+            ("status", Json::from(status)),
+            ("error", Json::from(error)),
+        ])
+    }
+}
+
+/// Parse a range of days like "1..=10" or "20..25", or "all" for every day
+pub fn days(s: &str) -> Option<RangeInclusive<usize>> {
+    if s == "all" {
+        return Some(1..=usize::MAX);
+    }
+    let (from, to) = s.split_once("..")?;
+    let from = from.parse().ok()?;
+    if let Some(to) = to.strip_prefix('=') {
+        Some(from..=to.parse().ok()?)
+    } else {
+        let to: usize = to.parse().ok()?;
+        Some(from..=to.checked_sub(1)?)
+    }
+}
+
+// Panics are reported afterwards rather than as they happen
+fn quietly<R>(f: impl FnOnce() -> R) -> R {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = f();
+    panic::set_hook(hook);
+    result
+}
+
+/// Run every Part for the chosen days, even if some of them panic or time out
+pub fn run_all(
+    parts: &[Part],
+    days: RangeInclusive<usize>,
+    input: &Input,
+    timeout: Option<Duration>,
+) -> Vec<Report> {
+    quietly(|| {
+        parts
+            .iter()
+            .filter(|part| days.contains(&part.number()))
+            .map(|part| part.run(input, timeout))
+            .collect()
+    })
+}
+
+/// Run every solver of each Part with variants for the chosen days, printing whether they
+/// agree, and returning true only if none of them disagreed
+///
+/// The input for each Part is read once and shared by all its solvers. A solver which panics
+/// disagrees with the others, but if the input can't be read the Part is missing
+pub fn compare(
+    parts: &[Part],
+    days: RangeInclusive<usize>,
+    input: &Input,
+    timeout: Option<Duration>,
+) -> bool {
+    let (mut agree, mut differ, mut missing) = (0, 0, 0);
+    for part in parts.iter() {
+        if !days.contains(&part.number()) || part.variants.is_empty() {
+            continue;
+        }
+        let name = part.name();
+        let ctxt = match input.try_contents(&name) {
+            Ok(ctxt) => ctxt,
+            Err(e) => {
+                missing += 1;
+                println!("{name} missing {e}");
+                continue;
+            }
+        };
+        let reports: Vec<(&str, Report)> = quietly(|| {
+            part.solvers()
+                .map(|(solver, f)| (solver, Part { solver: f, ..*part }.run_with(&ctxt, timeout)))
+                .collect()
+        });
+        let answers: Vec<String> = reports
+            .iter()
+            .map(|(solver, report)| match &report.outcome {
+                Outcome::Solved(answer) => format!("{solver} {answer}"),
+                Outcome::Panicked(message) => format!("{solver} FAILED: {message}"),
+                Outcome::Unreadable(e) => format!("{solver} {e}"),
+                Outcome::Malformed(e) => format!("{solver} FAILED: {e}"),
+            })
+            .collect();
+        let (_, first) = &reports[0];
+        match &first.outcome {
+            Outcome::Solved(answer)
+                if reports.iter().all(|(_, report)| {
+                    matches!(&report.outcome, Outcome::Solved(other) if other == answer)
+                }) =>
+            {
+                agree += 1;
+                let solvers: Vec<&str> = reports.iter().map(|&(solver, _)| solver).collect();
+                println!("{name} agree   {answer} ({})", solvers.join(", "));
+            }
+            _ => {
+                differ += 1;
+                println!("{name} DIFFER  {}", answers.join(", "));
+            }
+        }
+    }
+    println!("{agree} agreed, {differ} differed, {missing} missing");
+    differ == 0
+}
+
+/// Print a table of day, part, answer and time for each Report
+pub fn table(reports: &[Report]) {
+    let answers: Vec<String> = reports
+        .iter()
+        .map(|report| match &report.outcome {
+            Outcome::Solved(answer) => answer.clone(),
+            Outcome::Panicked(message) => format!("FAILED: {message}"),
+            Outcome::Unreadable(e) => format!("FAILED: {e}"),
+            Outcome::Malformed(e) => format!("FAILED: {e}"),
+        })
+        .collect();
+    let width = answers.iter().map(|a| a.len()).max().unwrap_or(0).max(6);
+
+    println!("Day Part {:width$} Time", "Answer");
+    for (report, answer) in reports.iter().zip(answers) {
+        println!(
+            "{:>3} {:<4} {answer:width$} {:.3?}",
+            report.part.number(),
+            report.part.part,
+            report.elapsed
+        );
+    }
+    let failed = reports
+        .iter()
+        .filter(|report| !matches!(report.outcome, Outcome::Solved(_)))
+        .count();
+    let total: Duration = reports.iter().map(|report| report.elapsed).sum();
+    println!(
+        "{} parts, {failed} failed, {total:.3?} in total",
+        reports.len()
+    );
+    for report in reports {
+        if let Outcome::Malformed(e) = &report.outcome {
+            println!("\n{}: {}", report.part.name(), e.excerpt());
+        }
+    }
+}
+
+/// Summary of how long repeated runs took
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Statistics {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Statistics {
+    pub fn new(times: &[Duration]) -> Self {
+        assert!(!times.is_empty(), "Statistics need at least one timing");
+        let mut sorted = times.to_vec();
+        sorted.sort_unstable();
+        let runs = sorted.len();
+        let min = sorted[0];
+        let median = if runs % 2 == 1 {
+            sorted[runs / 2]
+        } else {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        };
+        let mean = sorted.iter().sum::<Duration>() / runs as u32;
+        let variance = sorted
+            .iter()
+            .map(|t| (t.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+        let stddev = Duration::from_secs_f64(variance.sqrt());
+        Self {
+            runs,
+            min,
+            median,
+            mean,
+            stddev,
+        }
+    }
+}
+
+use std::fmt;
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} runs: min {:.3?} median {:.3?} mean {:.3?} stddev {:.3?}",
+            self.runs, self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+/// Time parsing the input for a Part once, then after some warmup runs, time solving the
+/// parsed input repeatedly, with searches giving up if the whole bench exceeds the timeout
+pub fn bench(
+    part: &Part,
+    input: &Input,
+    warmup: usize,
+    iterations: usize,
+    timeout: Option<Duration>,
+) -> Result<(Duration, Statistics), Error> {
+    let ctxt = input.try_contents(&part.name())?;
+    let timed = deadline(timeout).enforce(|| (part.solver)(&ctxt, warmup + iterations))?;
+    Ok((timed.parse, Statistics::new(&timed.solves[warmup..])))
+}
+
+/// Expected answers for each Part of one year, keyed by name e.g. "day05b", each year has its
+/// own answers file, see Input::answers
+///
+/// The answers file has one line per Part, its name then a space and then the answer
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<String, String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut answers = BTreeMap::new();
+        for (n, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let Some((name, answer)) = line.split_once(' ') else {
+                return Err(Error::parse(n + 1, line, "a name and an answer"));
+            };
+            answers.insert(String::from(name), String::from(answer));
+        }
+        Ok(Self { answers })
+    }
+
+    /// Read an answers file, if there is no such file there are no answers yet
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::io(path.display(), e)),
+        }
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        fs::write(path, self.to_string()).map_err(|e| Error::io(path.display(), e))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.answers.get(name).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, name: String, answer: String) {
+        self.answers.insert(name, answer);
+    }
+
+    /// Record the answer from each Report which solved its Part
+    pub fn record(&mut self, reports: &[Report]) {
+        for report in reports {
+            if let Outcome::Solved(answer) = &report.outcome {
+                self.insert(report.part.name(), answer.clone());
+            }
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, answer) in self.answers.iter() {
+            writeln!(f, "{name} {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Print pass, fail or missing for each Report compared to the expected Answers, returning
+/// true only if nothing failed
+///
+/// A Part whose input can't be read, or which has no recorded answer, is missing rather than
+/// failed, but one which panicked has failed
+pub fn check(reports: &[Report], answers: &Answers) -> bool {
+    let (mut pass, mut fail, mut missing) = (0, 0, 0);
+    for report in reports {
+        let name = report.part.name();
+        match (&report.outcome, answers.get(&name)) {
+            (Outcome::Solved(answer), Some(expected)) if answer == expected => {
+                pass += 1;
+                println!("{name} pass    {answer}");
+            }
+            (Outcome::Solved(answer), Some(expected)) => {
+                fail += 1;
+                println!("{name} FAIL    {answer} but expected {expected}");
+            }
+            (Outcome::Solved(answer), None) => {
+                missing += 1;
+                println!("{name} missing {answer}");
+            }
+            (Outcome::Panicked(message), _) => {
+                fail += 1;
+                println!("{name} FAIL    {message}");
+            }
+            (Outcome::Unreadable(e), _) => {
+                missing += 1;
+                println!("{name} missing {e}");
+            }
+            (Outcome::Malformed(e), _) => {
+                fail += 1;
+                println!("{name} FAIL    {e}");
+            }
+        }
+    }
+    println!("{pass} passed, {fail} failed, {missing} missing");
+    fail == 0
+}
+
+/// Directory of the example inputs from each day's puzzle, in a directory for each year with
+/// their answers in answers.txt
+pub const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+
+/// Solve the named Part (e.g. "day05b") with its example input from the fixtures and check
+/// the answer matches the one recorded there, with each of the Part's solvers, or only with
+/// its example solver if it has one
+pub fn fixture(year: &Year, name: &str) {
+    let part = year
+        .find(name)
+        .unwrap_or_else(|| panic!("{name} is not registered for {}", year.year));
+    if let Some(f) = part.example {
+        return example(year, name, "example", f);
+    }
+    for (solver, f) in part.solvers() {
+        example(year, name, solver, f);
+    }
+}
+
+/// Solve the named Part's example input from the fixtures with this solver and check the
+/// answer matches the one recorded there
+pub fn example(year: &Year, name: &str, solver: &str, f: Solve) {
+    let dir = Path::new(FIXTURES).join(year.year.to_string());
+    let answers = Answers::read(&dir.join("answers.txt")).unwrap_or_else(|e| panic!("{e}"));
+    let Some(expected) = answers.get(name) else {
+        panic!("{name} has no example answer");
+    };
+    let ctxt = Input::Directory(dir).contents(name);
+    let answer = match f(&ctxt, 1) {
+        Ok(timed) => timed.answer,
+        Err(e) => panic!("{name} example is malformed\n{}", e.excerpt()),
+    };
+    assert_eq!(answer, expected, "{name} example with {solver} solver");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges() {
+        assert_eq!(days("1..=10"), Some(1..=10));
+        assert_eq!(days("20..25"), Some(20..=24));
+        assert_eq!(days("5b"), None);
+    }
+
+    #[test]
+    fn solvers() {
+        let part = Part {
+            day: "day01",
+            part: 'a',
+            solver: |ctxt, times| timed(ctxt, |_| Ok(()), |_: &()| "slow", times),
+            variants: &[Variant {
+                name: "fast",
+                solver: |ctxt, times| timed(ctxt, |_| Ok(()), |_: &()| "fast", times),
+            }],
+            example: None,
+        };
+        let ctxt = Contents {
+            text: String::new(),
+        };
+        let names: Vec<&str> = part.solvers().map(|(name, _)| name).collect();
+        assert_eq!(names, [DEFAULT, "fast"]);
+        let fast = part.with_solver("fast").unwrap();
+        assert_eq!((fast.solver)(&ctxt, 1).unwrap().answer, "fast");
+        let slow = part.with_solver(DEFAULT).unwrap();
+        assert_eq!((slow.solver)(&ctxt, 3).unwrap().solves.len(), 3);
+        assert_eq!((slow.solver)(&ctxt, 0).unwrap().answer, "slow");
+        assert!(part.with_solver("medium").is_none());
+    }
+
+    #[test]
+    fn statistics() {
+        let ms = Duration::from_millis;
+        let stats = Statistics::new(&[ms(4), ms(2), ms(6), ms(8)]);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mean, ms(5));
+        let stddev = stats.stddev.as_secs_f64() * 1000.0;
+        assert!((stddev - 5.0f64.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn answers() {
+        let answers = Answers::parse("day01a 142\n\nday25b Happy Christmas!\n").unwrap();
+        assert_eq!(answers.get("day01a"), Some("142"));
+        assert_eq!(answers.get("day25b"), Some("Happy Christmas!"));
+        assert_eq!(answers.get("day01b"), None);
+        assert_eq!(answers.to_string(), "day01a 142\nday25b Happy Christmas!\n");
+        assert!(Answers::parse("day01a\n").is_err());
+
+        let fixtures = Input::Directory(FIXTURES.into());
+        let path = Path::new(FIXTURES);
+        assert_eq!(fixtures.answers(2023), path.join("2023/answers.txt"));
+        assert_eq!(fixtures.answers(2015), path.join("answers-2015.txt"));
+    }
+}
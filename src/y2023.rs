@@ -0,0 +1,147 @@
+use crate::runner::{Part, Year};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Both parts of every day's puzzle
+pub const PARTS: [Part; 50] = crate::days!(
+    day01,
+    day02,
+    day03,
+    day04,
+    day05,
+    day06 { parse_b: parse_kerned },
+    day07 { parse_b: parse_wild },
+    day08,
+    day09,
+    day10,
+    day11,
+    day12 { a: [memo => a_memo] },
+    day13,
+    day14,
+    day15,
+    day16,
+    day17 { a: [astar => a_astar], b: [astar => b_astar] },
+    day18 { a: [compressed => a_compressed], parse_b: parse_hex },
+    day19,
+    day20,
+    day21,
+    day22,
+    day23,
+    day24 { example_a: a_example },
+    day25,
+);
+
+pub const YEAR: Year = Year {
+    year: 2023,
+    parts: &PARTS,
+};
+
+#[cfg(test)]
+mod fixtures {
+    crate::fixtures!(
+        crate::y2023::YEAR; day01, day02, day03, day04, day05, day06, day07, day08, day09, day10,
+        day11, day12, day13, day14, day15, day16, day17, day18, day19,
+        day20 { b: ignore },
+        day21 { b: ignore },
+        day22, day23,
+        day24 { b: ignore },
+        day25,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::{Outcome, FIXTURES};
+    use crate::y2023::YEAR;
+    use crate::y2023::{day17, day21};
+    use crate::{Budget, Input};
+    use std::panic;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test]
+    fn tiled() {
+        let ctxt = Input::Directory(PathBuf::from(FIXTURES).join("2023")).contents("day21");
+        let garden = day21::parse(&ctxt).unwrap();
+        assert_eq!(day21::tiled(&garden, 6), 16);
+        assert_eq!(day21::tiled(&garden, 10), 50);
+        assert_eq!(day21::tiled(&garden, 50), 1594);
+        assert_eq!(day21::tiled(&garden, 100), 6536);
+    }
+
+    #[test]
+    fn diamond() {
+        // Shaped like real inputs, 131 wide with the start in the middle, and with clear
+        // edges, middle row and middle column, but scattered rocks elsewhere
+        let mut text = String::new();
+        for y in 0..131 {
+            for x in 0..131 {
+                let clear = x == 0 || y == 0 || x == 130 || y == 130 || x == 65 || y == 65;
+                text.push(match (x, y) {
+                    (65, 65) => 'S',
+                    _ if !clear && (x * 7 + y * 13) % 23 == 0 => '#',
+                    _ => '.',
+                });
+            }
+            text.push('\n');
+        }
+        let garden: day21::Garden = text.parse().unwrap();
+        assert_eq!(day21::diamond(&garden, 327), day21::tiled(&garden, 327));
+    }
+
+    #[test]
+    fn budget() {
+        let ctxt = Input::Directory(PathBuf::from(FIXTURES).join("2023")).contents("day17");
+        let factory = day17::parse(&ctxt).unwrap();
+        let ample = Budget {
+            states: Some(10_000),
+            ..Budget::default()
+        };
+        assert_eq!(ample.enforce(|| day17::a_astar(&factory)), 102);
+        let small = Budget {
+            states: Some(100),
+            ..Budget::default()
+        };
+        let stopped = panic::catch_unwind(|| small.enforce(|| day17::a_astar(&factory)));
+        let message = *stopped.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.ends_with("the search exceeded the most states"));
+        assert_eq!(Budget::current(), Budget::default());
+    }
+
+    #[test]
+    fn timeout() {
+        let ctxt = Input::Directory(PathBuf::from(FIXTURES).join("2023")).contents("day17");
+        let astar = YEAR.find("day17b").unwrap().with_solver("astar").unwrap();
+        let report = astar.run_with(&ctxt, Some(Duration::ZERO));
+        let Outcome::Panicked(message) = report.outcome else {
+            panic!("A search with no time at all should give up");
+        };
+        assert!(message.ends_with("the search exceeded the deadline"));
+        let report = astar.run_with(&ctxt, Some(Duration::from_secs(60)));
+        assert!(matches!(report.outcome, Outcome::Solved(answer) if answer == "94"));
+    }
+}
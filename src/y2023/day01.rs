@@ -1,30 +1,34 @@
-use sky::readfile;
+use crate::{Contents, ParseError};
 
-type Number = u32;
+pub type Number = u32;
 
-pub fn a() {
-    let ctxt = readfile("01");
-    let mut sum = 0;
-    for line in ctxt.lines() {
-        let digits: Vec<&str> = line.matches(char::is_numeric).collect();
-        let Some(first) = digits
-            .first()
-            .and_then(|s| s.chars().next())
-            .and_then(|c| c.to_digit(10))
-        else {
-            panic!("No digits in {line}");
-        };
-        let Some(last) = digits
-            .last()
-            .and_then(|s| s.chars().next())
-            .and_then(|c| c.to_digit(10))
-        else {
-            panic!("No digits in {line} even though there were last time?");
-        };
-        let value = first * 10 + last;
-        sum += value;
-    }
-    println!("Sum of calibration values is {sum}");
+/// Calibration value from the first and last digits on the line
+pub fn calibration(line: &str) -> Number {
+    let digits: Vec<&str> = line.matches(char::is_numeric).collect();
+    let Some(first) = digits
+        .first()
+        .and_then(|s| s.chars().next())
+        .and_then(|c| c.to_digit(10))
+    else {
+        panic!("No digits in {line}");
+    };
+    let Some(last) = digits
+        .last()
+        .and_then(|s| s.chars().next())
+        .and_then(|c| c.to_digit(10))
+    else {
+        panic!("No digits in {line} even though there were last time?");
+    };
+    first * 10 + last
+}
+
+/// Each line of the calibration document
+pub fn parse(ctxt: &Contents) -> Result<Vec<&str>, ParseError> {
+    Ok(ctxt.lines().collect())
+}
+
+pub fn a(lines: &[&str]) -> Number {
+    lines.iter().map(|line| calibration(line)).sum()
 }
 
 fn elf_first(s: &str) -> Number {
@@ -113,16 +117,11 @@ fn elf_last(s: &str) -> Number {
     }
 }
 
-fn elf_digits(s: &str) -> Number {
+/// Calibration value from the first and last digits on the line, some spelled out as words
+pub fn elf_digits(s: &str) -> Number {
     elf_first(s) * 10 + elf_last(s)
 }
 
-pub fn b() {
-    let ctxt = readfile("01");
-    let mut sum = 0;
-    for line in ctxt.lines() {
-        let value = elf_digits(line);
-        sum += value;
-    }
-    println!("Actually sum of calibration values is {sum}");
+pub fn b(lines: &[&str]) -> Number {
+    lines.iter().map(|line| elf_digits(line)).sum()
 }
@@ -1,17 +1,17 @@
-use sky::readfile;
+use crate::{Contents, ParseError};
 
-type Number = u32;
+pub type Number = u32;
 
 #[derive(Copy, Clone, Debug)]
-struct Round {
-    red: Number,
-    green: Number,
-    blue: Number,
+pub struct Round {
+    pub red: Number,
+    pub green: Number,
+    pub blue: Number,
 }
 
 use std::str::FromStr;
 impl FromStr for Round {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut red = 0;
@@ -20,10 +20,10 @@ impl FromStr for Round {
         let split = s.split(", ");
         for pair in split {
             let Some((amount, color)) = pair.split_once(' ') else {
-                return Err("No space in pair");
+                return Err(ParseError::new(s, pair, "an amount and a color"));
             };
             let Ok(amount): Result<Number, _> = amount.parse() else {
-                return Err("Amount isn't a number");
+                return Err(ParseError::new(s, amount, "an amount"));
             };
             match color {
                 "red" => {
@@ -36,7 +36,7 @@ impl FromStr for Round {
                     blue += amount;
                 }
                 _ => {
-                    return Err("Unacceptable color");
+                    return Err(ParseError::new(s, color, "red, green or blue"));
                 }
             }
         }
@@ -46,30 +46,28 @@ impl FromStr for Round {
 }
 
 #[derive(Debug)]
-struct Game {
-    id: Number,
-    rounds: Vec<Round>,
+pub struct Game {
+    pub id: Number,
+    pub rounds: Vec<Round>,
 }
 
 impl FromStr for Game {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let Some((game, rounds)) = s.split_once(": ") else {
-            return Err("Cannot discern game ID");
+            return Err(ParseError::new(s, s, "a game ID then a colon"));
         };
         let Some(id) = game.strip_prefix("Game ") else {
-            return Err("Game ID missing");
+            return Err(ParseError::new(s, game, "Game"));
         };
         let Ok(id): Result<Number, _> = id.parse() else {
-            return Err("Game ID not numeric");
+            return Err(ParseError::new(s, id, "a game ID"));
         };
         let split = rounds.split("; ");
         let mut rounds = Vec::new();
         for round in split {
-            let Ok(round): Result<Round, _> = round.parse() else {
-                return Err("Can't parse round");
-            };
+            let round: Round = round.parse().map_err(|e: ParseError| e.within(s, round))?;
             rounds.push(round);
         }
 
@@ -78,7 +76,8 @@ impl FromStr for Game {
 }
 
 impl Game {
-    fn possible(&self, red: Number, green: Number, blue: Number) -> bool {
+    /// Whether every round could be drawn from a bag with these cubes
+    pub fn possible(&self, red: Number, green: Number, blue: Number) -> bool {
         for r in &self.rounds {
             if r.red > red || r.green > green || r.blue > blue {
                 return false;
@@ -87,7 +86,8 @@ impl Game {
         true
     }
 
-    fn power(&self) -> Number {
+    /// Power of the fewest cubes which make every round possible
+    pub fn power(&self) -> Number {
         let mut red = 0;
         let mut green = 0;
         let mut blue = 0;
@@ -101,28 +101,18 @@ impl Game {
     }
 }
 
-pub fn a() {
-    let ctxt = readfile("02");
-    let mut sum = 0;
-    for line in ctxt.lines() {
-        let Ok(game): Result<Game, &str> = line.parse() else {
-            panic!("Couldn't parse: {line}");
-        };
-        if game.possible(12, 13, 14) {
-            sum += game.id
-        }
-    }
-    println!("Sum of IDs of possible games is {sum}");
+pub fn parse(ctxt: &Contents) -> Result<Vec<Game>, ParseError> {
+    ctxt.parsed()
 }
 
-pub fn b() {
-    let ctxt = readfile("02");
-    let mut sum = 0;
-    for line in ctxt.lines() {
-        let Ok(game): Result<Game, &str> = line.parse() else {
-            panic!("Couldn't parse: {line}");
-        };
-        sum += game.power();
-    }
-    println!("Sum of powers of games is {sum}");
+pub fn a(games: &[Game]) -> Number {
+    games
+        .iter()
+        .filter(|game| game.possible(12, 13, 14))
+        .map(|game| game.id)
+        .sum()
+}
+
+pub fn b(games: &[Game]) -> Number {
+    games.iter().map(Game::power).sum()
 }
@@ -1,10 +1,10 @@
-use sky::map::Map;
-use sky::readfile;
+use crate::map::Map;
+use crate::{Contents, ParseError};
 
-type Number = u32;
+pub type Number = u32;
 
 #[derive(Copy, Clone, Default, Eq, PartialEq)]
-enum Code {
+pub enum Code {
     #[default]
     Empty,
     Digit(Number),
@@ -21,7 +21,7 @@ impl From<char> for Code {
     }
 }
 
-type Schematic = Map<Code>;
+pub type Schematic = Map<Code>;
 
 use std::fmt::{Display, Formatter};
 
@@ -35,9 +35,11 @@ impl Display for Code {
     }
 }
 
-pub fn a() {
-    let ctxt = readfile("03");
-    let schematic: Schematic = ctxt.value().parse().unwrap();
+pub fn parse(ctxt: &Contents) -> Result<Schematic, ParseError> {
+    ctxt.grid()
+}
+
+pub fn a(schematic: &Schematic) -> Number {
     let numbers = schematic.find(|code| matches!(code, Code::Digit(_)));
     let mut sum = 0;
     for (x, y) in numbers {
@@ -73,7 +75,7 @@ pub fn a() {
             }
         }
     }
-    println!("Sum of all part numbers is: {sum}");
+    sum
 }
 
 fn read_number(schematic: &Schematic, mut x: isize, y: isize) -> Number {
@@ -90,50 +92,17 @@ fn read_number(schematic: &Schematic, mut x: isize, y: isize) -> Number {
     n
 }
 
-fn check_one(schematic: &Schematic, x: isize, y: isize) -> Option<Number> {
-    let code = schematic.read(x, y).unwrap_or_default();
-    match code {
-        Code::Digit(_) => Some(read_number(schematic, x, y)),
-        _ => None,
-    }
-}
-
-fn check_gear(schematic: &Schematic, x: isize, y: isize) -> Option<Number> {
-    let mut nums: Vec<Number> = Vec::new();
-
-    // left
-    if let Some(n) = check_one(schematic, x - 1, y) {
-        nums.push(n);
-    }
-
-    // right
-    if let Some(n) = check_one(schematic, x + 1, y) {
-        nums.push(n);
-    }
-
-    // above
-    if let Some(n) = check_one(schematic, x, y - 1) {
-        nums.push(n);
-    } else {
-        if let Some(n) = check_one(schematic, x - 1, y - 1) {
-            nums.push(n);
-        }
-        if let Some(n) = check_one(schematic, x + 1, y - 1) {
-            nums.push(n);
-        }
-    }
-
-    // below
-    if let Some(n) = check_one(schematic, x, y + 1) {
-        nums.push(n);
-    } else {
-        if let Some(n) = check_one(schematic, x - 1, y + 1) {
-            nums.push(n);
-        }
-        if let Some(n) = check_one(schematic, x + 1, y + 1) {
-            nums.push(n);
-        }
-    }
+/// Gear ratio if the symbol at (x, y) is next to exactly two part numbers
+pub fn check_gear(schematic: &Schematic, x: isize, y: isize) -> Option<Number> {
+    // Each number is read from the leftmost of its digits next to the symbol
+    let nums: Vec<Number> = schematic
+        .neighbours8(x, y)
+        .filter(|&(nx, ny, code)| {
+            matches!(code, Code::Digit(_))
+                && !(nx > x - 1 && matches!(schematic.read(nx - 1, ny), Some(Code::Digit(_))))
+        })
+        .map(|(nx, ny, _)| read_number(schematic, nx, ny))
+        .collect();
 
     if nums.len() == 2 {
         Some(nums.into_iter().product())
@@ -142,17 +111,15 @@ fn check_gear(schematic: &Schematic, x: isize, y: isize) -> Option<Number> {
     }
 }
 
-pub fn b() {
-    let ctxt = readfile("03");
-    let schematic: Schematic = ctxt.value().parse().unwrap();
+pub fn b(schematic: &Schematic) -> Number {
     // Find the gears, start with '*'
     let possible = schematic.find(|code| matches!(code, Code::Symbol('*')));
     let mut sum = 0;
     for (x, y) in possible {
-        let Some(ratio) = check_gear(&schematic, x, y) else {
+        let Some(ratio) = check_gear(schematic, x, y) else {
             continue;
         };
         sum += ratio;
     }
-    println!("Sum of all gear ratios is: {sum}");
+    sum
 }
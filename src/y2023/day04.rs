@@ -0,0 +1,88 @@
+use crate::{Contents, ParseError};
+
+pub type Number = u32;
+
+#[derive(Debug)]
+pub struct Card {
+    pub left: Vec<Number>,
+    pub right: Vec<Number>,
+}
+
+use std::str::FromStr;
+fn numbers(s: &str, list: &str) -> Result<Vec<Number>, ParseError> {
+    list.split_ascii_whitespace()
+        .map(|n| n.parse().map_err(|_| ParseError::new(s, n, "a number")))
+        .collect()
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((_, rest)) = s.split_once(": ") else {
+            return Err(ParseError::new(s, s, "a card number then a colon"));
+        };
+
+        let Some((left, right)) = rest.split_once(" | ") else {
+            return Err(ParseError::new(s, rest, "numbers divided by a bar"));
+        };
+
+        let left = numbers(s, left)?;
+        let right = numbers(s, right)?;
+
+        Ok(Card { left, right })
+    }
+}
+
+impl Card {
+    pub fn points(&self) -> Number {
+        let mut p = 0;
+        for n in &self.right {
+            if self.left.contains(n) {
+                if p == 0 {
+                    p = 1;
+                } else {
+                    p *= 2;
+                }
+            }
+        }
+        p
+    }
+
+    /// How many of the numbers we have are winning numbers
+    pub fn matches(&self) -> Number {
+        let mut p = 0;
+        for n in &self.right {
+            if self.left.contains(n) {
+                p += 1;
+            }
+        }
+        p
+    }
+}
+
+pub fn parse(ctxt: &Contents) -> Result<Vec<Card>, ParseError> {
+    ctxt.parsed()
+}
+
+pub fn a(cards: &[Card]) -> Number {
+    cards.iter().map(Card::points).sum()
+}
+
+pub fn b(cards: &[Card]) -> Number {
+    let mut copies = Vec::new();
+    copies.resize(cards.len(), 1);
+
+    let mut count = 0;
+    for k in 0..cards.len() {
+        let n = copies[k];
+        let m = cards[k].matches() as usize;
+        for j in 1..=m {
+            copies[k + j] += n;
+        }
+
+        count += n;
+    }
+
+    count
+}
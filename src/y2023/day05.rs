@@ -0,0 +1,193 @@
+use crate::{Contents, ParseError};
+
+pub type Number = u64;
+
+// Inclusive
+#[derive(Copy, Clone, Debug)]
+pub struct Numbers {
+    pub from: Number,
+    pub to: Number,
+}
+
+impl Numbers {
+    // cut gives three optional Numbers::  before, transformed, after
+    pub fn cut(
+        self,
+        src: Number,
+        len: Number,
+        dest: Number,
+    ) -> (Option<Self>, Option<Self>, Option<Self>) {
+        if src > self.to {
+            return (Some(self), None, None);
+        }
+        if src + len < self.from {
+            return (None, None, Some(self));
+        }
+
+        let before = if src > self.from {
+            Some(Self {
+                from: self.from,
+                to: src - 1,
+            })
+        } else {
+            None
+        };
+
+        let after = if src + len < self.to {
+            Some(Self {
+                from: src + len + 1,
+                to: self.to,
+            })
+        } else {
+            None
+        };
+
+        let left = if src > self.from { src } else { self.from };
+        let right = if src + len < self.to {
+            src + len
+        } else {
+            self.to
+        };
+
+        let transformed = Some(Self {
+            from: left + dest - src,
+            to: right + dest - src,
+        });
+
+        (before, transformed, after)
+    }
+}
+
+/// One of the almanac's maps from one category of number to the next
+#[derive(Debug)]
+pub struct Map {
+    dest: Vec<Number>,
+    src: Vec<Number>,
+    len: Vec<Number>,
+}
+
+impl Map {
+    /// The map in this section of the almanac, its name and then a line for each range, with
+    /// any error on its line counting from the start of the section
+    pub fn read(section: &str) -> Result<Self, ParseError> {
+        let mut dest = Vec::new();
+        let mut src = Vec::new();
+        let mut len = Vec::new();
+
+        let mut lines = section.lines().enumerate();
+        let name = lines.next().map_or(section, |(_, name)| name);
+        if !name.ends_with(" map:") {
+            return Err(ParseError::new(name, name, "the name of a map").line(1));
+        }
+        for (n, line) in lines {
+            let numbers = crate::fields(line, ' ').collect::<Result<Vec<Number>, _>>();
+            let [d, s, l] = numbers.map_err(|e| e.line(n + 1))?[..] else {
+                return Err(ParseError::new(line, line, "three numbers").line(n + 1));
+            };
+            dest.push(d);
+            src.push(s);
+            len.push(l);
+        }
+        Ok(Map { dest, src, len })
+    }
+
+    pub fn apply(&self, n: Number) -> Number {
+        for ((&s, &l), &d) in self.src.iter().zip(self.len.iter()).zip(self.dest.iter()) {
+            if n >= s && n <= s + l {
+                return (n + d) - s;
+            }
+        }
+        // If unmapped, the same
+        n
+    }
+
+    /// Map whole ranges of numbers, which may be cut into several ranges by the map
+    pub fn ranges(&self, mut input: Vec<Numbers>) -> Vec<Numbers> {
+        let mut out = Vec::new();
+
+        for ((&s, &l), &d) in self.src.iter().zip(self.len.iter()).zip(self.dest.iter()) {
+            let mut residue = Vec::new();
+            for numbers in input {
+                let (before, transformed, after) = numbers.cut(s, l, d);
+                if let Some(before) = before {
+                    residue.push(before);
+                }
+                if let Some(transformed) = transformed {
+                    out.push(transformed);
+                }
+                if let Some(after) = after {
+                    residue.push(after);
+                }
+            }
+            input = residue;
+        }
+
+        out.append(&mut input);
+        out
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<Number>,
+    /// Maps from seed to soil, soil to fertilizer, and so on until location
+    pub maps: Vec<Map>,
+}
+
+impl Almanac {
+    /// Seeds when the list is actually pairs of start and count
+    pub fn seed_ranges(&self) -> Vec<Numbers> {
+        if self.seeds.len() % 2 == 1 {
+            panic!("Uneven seed list cannot work as described in problem");
+        }
+        self.seeds
+            .chunks(2)
+            .map(|pair| Numbers {
+                from: pair[0],
+                to: pair[0] + pair[1] - 1,
+            })
+            .collect()
+    }
+
+    /// Location for a seed, after applying every map
+    pub fn location(&self, seed: Number) -> Number {
+        self.maps.iter().fold(seed, |n, map| map.apply(n))
+    }
+}
+
+/// The seeds, then each map in its own section
+pub fn parse(ctxt: &Contents) -> Result<Almanac, ParseError> {
+    let mut sections = ctxt.sections();
+    let Some(first) = sections.next() else {
+        return Err(ParseError::end("", "a line of seeds").line(1));
+    };
+    let Some(list) = first.strip_prefix("seeds:") else {
+        return Err(ParseError::at(first, first, "seeds:").starting_at(ctxt.line_of(first)));
+    };
+    let seeds = ctxt.ints(list).collect::<Result<_, _>>()?;
+    let maps = sections
+        .map(|section| Map::read(section).map_err(|e| e.starting_at(ctxt.line_of(section))))
+        .collect::<Result<_, _>>()?;
+    Ok(Almanac { seeds, maps })
+}
+
+pub fn a(almanac: &Almanac) -> Number {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| almanac.location(seed))
+        .min()
+        .expect("There should be at least one location")
+}
+
+pub fn b(almanac: &Almanac) -> Number {
+    let mut seeds = almanac.seed_ranges();
+    for step in almanac.maps.iter() {
+        seeds = step.ranges(seeds);
+    }
+    seeds
+        .iter()
+        .map(|n| n.from)
+        .min()
+        .expect("Should be at least one seed")
+}
@@ -0,0 +1,79 @@
+use crate::{Contents, ParseError};
+
+pub type Number = u64;
+
+/// A race lasting some time, and the best distance so far
+#[derive(Copy, Clone, Debug)]
+pub struct Race {
+    pub time: Number,
+    pub distance: Number,
+}
+
+/// How many ways there are to beat the best distance for a race of this duration
+pub fn beat(duration: Number, best: Number) -> usize {
+    (0..=duration)
+        .map(|t| t * (duration - t))
+        .filter(|&d| d > best)
+        .count()
+}
+
+// The time and distance lines, each with its line number and the text after its label
+fn lines(ctxt: &Contents) -> Result<[(usize, &str, &str); 2], ParseError> {
+    let mut lines = ctxt.lines();
+    let mut line = |n: usize, label: &'static str| {
+        let Some(line) = lines.next() else {
+            return Err(ParseError::end("", label).line(n));
+        };
+        match line.strip_prefix(label) {
+            Some(rest) => Ok((n, line, rest)),
+            None => Err(ParseError::new(line, line, label).line(n)),
+        }
+    };
+    Ok([line(1, "Time:")?, line(2, "Distance:")?])
+}
+
+/// Each race, reading the columns of times and distances separately
+pub fn parse(ctxt: &Contents) -> Result<Vec<Race>, ParseError> {
+    let [(_, _, times), (dn, distance, distances)] = lines(ctxt)?;
+    let times: Vec<Number> = ctxt.ints(times).collect::<Result<_, _>>()?;
+    let distances: Vec<Number> = ctxt.ints(distances).collect::<Result<_, _>>()?;
+    if times.len() != distances.len() {
+        let expected = "a distance for each time";
+        return Err(ParseError::new(distance, distance, expected).line(dn));
+    }
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+pub fn a(races: &[Race]) -> usize {
+    races
+        .iter()
+        .map(|race| beat(race.time, race.distance))
+        .product()
+}
+
+// Fix the kerning of the columns on line n of the input, to give a single Number
+fn fix_everything(n: usize, line: &str, columns: &str) -> Result<Number, ParseError> {
+    let columns = columns.trim();
+    let expected = "a number, once the kerning is fixed";
+    columns
+        .replace(' ', "")
+        .parse()
+        .map_err(|_| ParseError::new(line, columns, expected).line(n))
+}
+
+/// The single race, once the bad kerning is fixed
+pub fn parse_kerned(ctxt: &Contents) -> Result<Race, ParseError> {
+    let [(tn, time, times), (dn, distance, distances)] = lines(ctxt)?;
+    Ok(Race {
+        time: fix_everything(tn, time, times)?,
+        distance: fix_everything(dn, distance, distances)?,
+    })
+}
+
+pub fn b(race: &Race) -> usize {
+    beat(race.time, race.distance)
+}
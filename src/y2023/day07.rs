@@ -1,9 +1,9 @@
-use sky::readfile;
+use crate::{Contents, ParseError};
 
-type Number = u32;
+pub type Number = u32;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
-enum Card {
+pub enum Card {
     Two,
     Three,
     Four,
@@ -21,7 +21,7 @@ enum Card {
 
 use std::str::FromStr;
 impl FromStr for Card {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -38,13 +38,13 @@ impl FromStr for Card {
             "Q" => Ok(Self::Q),
             "K" => Ok(Self::K),
             "A" => Ok(Self::A),
-            _ => panic!("{s} is not a card"),
+            _ => Err(ParseError::new(s, s, "a card")),
         }
     }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
-enum Type {
+pub enum Type {
     High,
     Pair,
     Two,
@@ -55,7 +55,7 @@ enum Type {
 }
 
 impl Type {
-    fn strength(mut cards: [Card; 5]) -> Self {
+    pub fn strength(mut cards: [Card; 5]) -> Self {
         cards.sort();
         if cards[0] == cards[4] {
             return Self::Five;
@@ -98,52 +98,51 @@ impl Type {
 }
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
-struct Hand {
-    kind: Type,
-    cards: [Card; 5],
-    bid: Number,
+pub struct Hand {
+    pub kind: Type,
+    pub cards: [Card; 5],
+    pub bid: Number,
 }
 
 impl FromStr for Hand {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let Some((cards, bid)) = s.split_once(' ') else {
-            return Err("No space in line");
+        let Some((hand, bid)) = s.split_once(' ') else {
+            return Err(ParseError::new(s, s, "cards then a bid"));
         };
         let Ok(bid): Result<Number, _> = bid.parse() else {
-            return Err("Bid was not an integer");
+            return Err(ParseError::new(s, bid, "a bid"));
         };
-        let Ok(cards): Result<[Card; 5], _> = cards
+        let cards = hand
             .matches(|_c| true)
-            .filter_map(|s| str::parse::<Card>(s).ok())
-            .collect::<Vec<Card>>()
-            .try_into()
-        else {
-            panic!("Wrong number of cards in {cards}?");
+            .map(|c| c.parse().map_err(|e: ParseError| e.within(s, c)))
+            .collect::<Result<Vec<Card>, _>>()?;
+        let Ok(cards): Result<[Card; 5], _> = cards.try_into() else {
+            return Err(ParseError::new(s, hand, "five cards"));
         };
         let kind = Type::strength(cards);
         Ok(Self { kind, cards, bid })
     }
 }
 
-pub fn a() {
-    let ctxt = readfile("07");
-    let mut hands: Vec<Hand> = ctxt
-        .lines()
-        .filter_map(|s| str::parse::<Hand>(s).ok())
-        .collect();
+pub fn parse(ctxt: &Contents) -> Result<Vec<Hand>, ParseError> {
+    ctxt.parsed()
+}
+
+pub fn a(hands: &[Hand]) -> Number {
+    let mut hands: Vec<_> = hands.iter().collect();
     hands.sort();
     let mut winnings = 0;
     for (n, hand) in hands.into_iter().enumerate() {
         let rank: Number = 1 + (n as Number);
         winnings += hand.bid * rank;
     }
-    println!("Total winnings from these hands are {winnings}");
+    winnings
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
-enum NewCard {
+pub enum NewCard {
     Wildcard,
     Two,
     Three,
@@ -160,7 +159,7 @@ enum NewCard {
 }
 
 impl FromStr for NewCard {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -177,13 +176,14 @@ impl FromStr for NewCard {
             "Q" => Ok(Self::Q),
             "K" => Ok(Self::K),
             "A" => Ok(Self::A),
-            _ => panic!("{s} is not a card"),
+            _ => Err(ParseError::new(s, s, "a card")),
         }
     }
 }
 
 impl Type {
-    fn new_kind(cards: [NewCard; 5]) -> Self {
+    /// Strength of a hand where the jokers are wild
+    pub fn new_kind(cards: [NewCard; 5]) -> Self {
         let mut vec: Vec<NewCard> = cards.into_iter().collect();
         vec.sort();
         vec.retain(|&c| c != NewCard::Wildcard);
@@ -272,46 +272,46 @@ impl Type {
 }
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
-struct NewHand {
-    kind: Type,
-    cards: [NewCard; 5],
-    bid: Number,
+pub struct NewHand {
+    pub kind: Type,
+    pub cards: [NewCard; 5],
+    pub bid: Number,
 }
 
 impl FromStr for NewHand {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let Some((cards, bid)) = s.split_once(' ') else {
-            return Err("No space in line");
+        let Some((hand, bid)) = s.split_once(' ') else {
+            return Err(ParseError::new(s, s, "cards then a bid"));
         };
         let Ok(bid): Result<Number, _> = bid.parse() else {
-            return Err("Bid was not an integer");
+            return Err(ParseError::new(s, bid, "a bid"));
         };
-        let Ok(cards): Result<[NewCard; 5], _> = cards
+        let cards = hand
             .matches(|_c| true)
-            .filter_map(|s| str::parse::<NewCard>(s).ok())
-            .collect::<Vec<NewCard>>()
-            .try_into()
-        else {
-            panic!("Wrong number of cards in {cards}?");
+            .map(|c| c.parse().map_err(|e: ParseError| e.within(s, c)))
+            .collect::<Result<Vec<NewCard>, _>>()?;
+        let Ok(cards): Result<[NewCard; 5], _> = cards.try_into() else {
+            return Err(ParseError::new(s, hand, "five cards"));
         };
         let kind = Type::new_kind(cards);
         Ok(Self { kind, cards, bid })
     }
 }
 
-pub fn b() {
-    let ctxt = readfile("07");
-    let mut hands: Vec<NewHand> = ctxt
-        .lines()
-        .filter_map(|s| str::parse::<NewHand>(s).ok())
-        .collect();
+/// Every hand, with J read as a wildcard
+pub fn parse_wild(ctxt: &Contents) -> Result<Vec<NewHand>, ParseError> {
+    ctxt.parsed()
+}
+
+pub fn b(hands: &[NewHand]) -> Number {
+    let mut hands: Vec<_> = hands.iter().collect();
     hands.sort();
     let mut winnings = 0;
     for (n, hand) in hands.into_iter().enumerate() {
         let rank: Number = 1 + (n as Number);
         winnings += hand.bid * rank;
     }
-    println!("Total winnings from these hands with Jacks wild are {winnings}");
+    winnings
 }
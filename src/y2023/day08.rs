@@ -0,0 +1,148 @@
+use crate::{Contents, ParseError};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Ident {
+    pub tlc: [u8; 3],
+}
+
+use std::str::FromStr;
+impl FromStr for Ident {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 3 {
+            return Err(ParseError::new(s, s, "a three letter node"));
+        }
+        let tlc: [u8; 3] = bytes.try_into().unwrap();
+
+        Ok(Ident { tlc })
+    }
+}
+
+pub const AAA: Ident = Ident {
+    tlc: [b'A', b'A', b'A'],
+};
+pub const ZZZ: Ident = Ident {
+    tlc: [b'Z', b'Z', b'Z'],
+};
+
+/// Where to go, left or right, from a node
+pub type Choice = (Ident, Ident);
+pub type Network = HashMap<Ident, Choice>;
+
+use std::collections::HashMap;
+/// One line of the network, a node and the choice of where to go from it
+pub fn node(line: &str) -> Result<(Ident, Choice), ParseError> {
+    let ident = |s: &str| s.parse().map_err(|e: ParseError| e.within(line, s));
+    let Some((node, rest)) = line.split_once(" = ") else {
+        return Err(ParseError::new(line, line, "a node then ="));
+    };
+    let Some((left, right)) = rest.split_once(", ") else {
+        return Err(ParseError::new(line, rest, "a pair of nodes"));
+    };
+    let Some(left) = left.strip_prefix('(') else {
+        return Err(ParseError::new(line, left, "("));
+    };
+    let Some(right) = right.strip_suffix(')') else {
+        return Err(ParseError::end(line, ")"));
+    };
+    Ok((ident(node)?, (ident(left)?, ident(right)?)))
+}
+
+/// Where a walk through the network has got to, and which direction it follows next
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct Walker {
+    at: Ident,
+    turn: usize,
+}
+
+/// The directions, which repeat forever, and the network they navigate
+struct Map<'a> {
+    lr: &'a [u8],
+    network: &'a Network,
+}
+
+use crate::State;
+impl State<Map<'_>> for Walker {
+    fn describe(&self, _map: &Map) -> String {
+        format!("{} {}", String::from_utf8_lossy(&self.at.tlc), self.turn)
+    }
+
+    fn next(&self, map: &Map) -> Vec<Self> {
+        let choice = map
+            .network
+            .get(&self.at)
+            .expect("Looking up a node should find it in the network");
+        let at = match map.lr[self.turn] {
+            b'L' => choice.0,
+            b'R' => choice.1,
+            _ => unreachable!("Directions are checked when parsing"),
+        };
+        let turn = (self.turn + 1) % map.lr.len();
+        vec![Walker { at, turn }]
+    }
+}
+
+/// How many steps following the directions from start until done
+pub fn steps<D>(lr: &str, network: &Network, start: Ident, done: D) -> usize
+where
+    D: Fn(Ident) -> bool,
+{
+    let map = Map {
+        lr: lr.as_bytes(),
+        network,
+    };
+    let walker = Walker { at: start, turn: 0 };
+    match Walker::report(walker, |w| done(w.at), &map) {
+        Some(route) => route.stats.depth,
+        None => panic!("Walking from {} never finishes", walker.describe(&map)),
+    }
+}
+
+/// The left/right directions and the network they navigate
+pub fn parse(ctxt: &Contents) -> Result<(&str, Network), ParseError> {
+    let mut lines = ctxt.lines().enumerate();
+    let Some((_, lr)) = lines.next() else {
+        return Err(ParseError::end("", "a line of L and R directions").line(1));
+    };
+    if let Some(n) = lr.find(|ch| ch != 'L' && ch != 'R') {
+        let found = &lr[n..n + lr[n..].chars().next().map_or(0, char::len_utf8)];
+        return Err(ParseError::new(lr, found, "a direction, L or R").line(1));
+    }
+    if lr.is_empty() {
+        return Err(ParseError::end(lr, "a direction, L or R").line(1));
+    }
+    lines.next(); // Throw away blank line
+
+    let mut network = HashMap::new();
+    for (n, line) in lines {
+        let (ident, choice) = node(line).map_err(|e| e.line(n + 1))?;
+        network.insert(ident, choice);
+    }
+    Ok((lr, network))
+}
+
+pub fn a((lr, n): &(&str, Network)) -> usize {
+    steps(lr, n, AAA, |p| p == ZZZ)
+}
+
+fn find_starts(network: &Network) -> Vec<Ident> {
+    network
+        .keys()
+        .copied()
+        .filter(|i| i.tlc[2] == b'A')
+        .collect()
+}
+
+pub fn b((lr, n): &(&str, Network)) -> usize {
+    let pace = lr.len();
+    let starts = find_starts(n);
+    // In theory they could all converge earlier, but let's assume not
+    let mut best = pace;
+    for pos in starts {
+        let n = steps(lr, n, pos, |p| p.tlc[2] == b'Z');
+        best = num::Integer::lcm(&best, &n);
+    }
+    best
+}
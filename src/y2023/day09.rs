@@ -0,0 +1,54 @@
+use crate::{Contents, ParseError};
+
+pub type Number = i32;
+
+fn diff(values: &[Number]) -> Vec<Number> {
+    let mut d = Vec::with_capacity(values.len() - 1);
+
+    for pair in values.windows(2) {
+        d.push(pair[1] - pair[0]);
+    }
+    d
+}
+
+/// Extrapolate the next value from the differences
+pub fn guess_next(values: &[Number]) -> Number {
+    let d = diff(values);
+    if d.iter().all(|&n| n == 0) {
+        *values.last().unwrap()
+    } else {
+        values.last().unwrap() + guess_next(&d)
+    }
+}
+
+fn numbers(line: &str) -> Result<Vec<Number>, ParseError> {
+    line.split(' ')
+        .map(|s| s.parse().map_err(|_| ParseError::new(line, s, "a number")))
+        .collect()
+}
+
+/// Each line's history of values
+pub fn parse(ctxt: &Contents) -> Result<Vec<Vec<Number>>, ParseError> {
+    ctxt.lines()
+        .enumerate()
+        .map(|(n, line)| numbers(line).map_err(|e| e.line(n + 1)))
+        .collect()
+}
+
+pub fn a(histories: &[Vec<Number>]) -> Number {
+    histories.iter().map(|values| guess_next(values)).sum()
+}
+
+/// Extrapolate the previous value from the differences
+pub fn guess_prev(values: &[Number]) -> Number {
+    let d = diff(values);
+    if d.iter().all(|&n| n == 0) {
+        *values.first().unwrap()
+    } else {
+        values.first().unwrap() - guess_prev(&d)
+    }
+}
+
+pub fn b(histories: &[Vec<Number>]) -> Number {
+    histories.iter().map(|values| guess_prev(values)).sum()
+}
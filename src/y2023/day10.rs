@@ -1,8 +1,8 @@
-use sky::map::Map;
-use sky::readfile;
+use crate::map::Map;
+use crate::{Contents, ParseError};
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
-enum Pipe {
+pub enum Pipe {
     #[default]
     Ground,
     Vert,
@@ -14,9 +14,11 @@ enum Pipe {
     Start,
 }
 
-impl From<char> for Pipe {
-    fn from(ch: char) -> Self {
-        match ch {
+impl TryFrom<char> for Pipe {
+    type Error = ParseError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        Ok(match ch {
             '.' => Self::Ground,
             '|' => Self::Vert,
             '-' => Self::Horiz,
@@ -25,30 +27,24 @@ impl From<char> for Pipe {
             '7' => Self::Sw,
             'F' => Self::Se,
             'S' => Self::Start,
-            _ => panic!("Invalid map symbol {ch}"),
-        }
+            _ => return Err(ParseError::char(ch, "a pipe, one of .|-LJ7FS")),
+        })
     }
 }
 
-type Pipes = Map<Pipe>;
+pub type Pipes = Map<Pipe>;
 
 fn fix_start(pipes: &mut Pipes, x: isize, y: isize) {
-    let west = matches!(
-        pipes.read(x - 1, y).unwrap_or_default(),
-        Pipe::Horiz | Pipe::Ne | Pipe::Se
-    );
-    let east = matches!(
-        pipes.read(x + 1, y).unwrap_or_default(),
-        Pipe::Horiz | Pipe::Nw | Pipe::Sw
-    );
-    let north = matches!(
-        pipes.read(x, y - 1).unwrap_or_default(),
-        Pipe::Vert | Pipe::Sw | Pipe::Se
-    );
-    let south = matches!(
-        pipes.read(x, y + 1).unwrap_or_default(),
-        Pipe::Vert | Pipe::Ne | Pipe::Nw
-    );
+    let (mut north, mut west, mut south, mut east) = (false, false, false, false);
+    for (nx, ny, pipe) in pipes.neighbours4(x, y) {
+        match (nx - x, ny - y, pipe) {
+            (-1, 0, Pipe::Horiz | Pipe::Ne | Pipe::Se) => west = true,
+            (1, 0, Pipe::Horiz | Pipe::Nw | Pipe::Sw) => east = true,
+            (0, -1, Pipe::Vert | Pipe::Sw | Pipe::Se) => north = true,
+            (0, 1, Pipe::Vert | Pipe::Ne | Pipe::Nw) => south = true,
+            _ => {}
+        }
+    }
 
     match (north, west, south, east) {
         (true, true, false, false) => pipes.write(x, y, Pipe::Nw),
@@ -69,8 +65,8 @@ enum Direction {
     West,
 }
 
-// Loop length
-fn length(pipes: &Pipes, sx: isize, sy: isize) -> usize {
+/// Length of the loop through the start
+pub fn length(pipes: &Pipes, sx: isize, sy: isize) -> usize {
     let mut x: isize = sx;
     let mut y: isize = sy;
     let mut distance = 0;
@@ -115,7 +111,8 @@ fn length(pipes: &Pipes, sx: isize, sy: isize) -> usize {
     }
 }
 
-fn just_loop(pipes: &Pipes, sx: isize, sy: isize) -> Pipes {
+/// Just the pipes making up the loop through the start
+pub fn just_loop(pipes: &Pipes, sx: isize, sy: isize) -> Pipes {
     let mut x: isize = sx;
     let mut y: isize = sy;
     let mut just = Map::new();
@@ -170,7 +167,7 @@ enum State {
 // If there are an *odd* number of Vertical pipes left of us, we're inside
 // if a pipe comes initially from the North, then tends East or West, but eventually turns South,
 // that's equivalent to a Vertical pipe, whereas if it turns back North it is not
-fn interior(pipes: &Pipes) -> usize {
+pub fn interior(pipes: &Pipes) -> usize {
     let mut n = 0;
     for y in pipes.y() {
         let mut inside = false;
@@ -216,29 +213,33 @@ fn interior(pipes: &Pipes) -> usize {
     n
 }
 
-pub fn a() {
-    let ctxt = readfile("10");
-    let mut pipes: Pipes = ctxt.value().parse().expect("Should be a map of pipes");
+/// The pipes, with the start replaced by whichever pipe fits there, and where the start was
+pub fn parse(ctxt: &Contents) -> Result<(Pipes, (isize, isize)), ParseError> {
+    let mut pipes: Pipes = ctxt.grid()?;
     let s = pipes.find(|p| p == Pipe::Start);
-    if s.len() != 1 {
-        panic!("Pipe networks with more than one Start can't be solved");
+    match s[..] {
+        [] => return Err(ParseError::end("", "a start, S")),
+        [_] => {}
+        [_, (x, y), ..] => {
+            // Pipes are all ASCII, so the column is also the byte offset
+            let line = ctxt.lines().nth(y as usize).expect("Every row is a line");
+            let x = x as usize;
+            let found = line.get(x..=x).unwrap_or(line);
+            let expected = "only one start, S";
+            return Err(ParseError::new(line, found, expected).line(ctxt.line_of(line)));
+        }
     }
-    let &(sx, sy) = s.first().unwrap();
+    let (sx, sy) = s[0];
     fix_start(&mut pipes, sx, sy);
-    let len = length(&pipes, sx, sy);
-    println!("Furthest from start is {} moves", len / 2);
+    Ok((pipes, (sx, sy)))
 }
 
-pub fn b() {
-    let ctxt = readfile("10");
-    let mut pipes: Pipes = ctxt.value().parse().expect("Should be a map of pipes");
-    let s = pipes.find(|p| p == Pipe::Start);
-    if s.len() != 1 {
-        panic!("Pipe networks with more than one Start can't be solved");
-    }
-    let &(sx, sy) = s.first().unwrap();
-    fix_start(&mut pipes, sx, sy);
-    let just = just_loop(&pipes, sx, sy);
-    let count = interior(&just);
-    println!("{count} tiles are enclosed by the loop");
+pub fn a((pipes, (sx, sy)): &(Pipes, (isize, isize))) -> usize {
+    let len = length(pipes, *sx, *sy);
+    len / 2
+}
+
+pub fn b((pipes, (sx, sy)): &(Pipes, (isize, isize))) -> usize {
+    let just = just_loop(pipes, *sx, *sy);
+    interior(&just)
 }
@@ -0,0 +1,121 @@
+use crate::map::Map;
+use crate::{Contents, ParseError};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Pixel {
+    #[default]
+    Space,
+    Galaxy,
+}
+
+impl TryFrom<char> for Pixel {
+    type Error = ParseError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        Ok(match ch {
+            '.' => Self::Space,
+            '#' => Self::Galaxy,
+            _ => return Err(ParseError::char(ch, "a pixel, . or #")),
+        })
+    }
+}
+
+pub type Pixels = Map<Pixel>;
+
+fn check_vertical(sky: &Pixels) -> Vec<isize> {
+    let mut v: Vec<isize> = Vec::new();
+    for x in sky.x() {
+        if sky
+            .y()
+            .all(|y| sky.read(x, y).unwrap_or_default() == Pixel::Space)
+        {
+            v.push(x);
+        }
+    }
+    v
+}
+
+fn check_horizontal(sky: &Pixels) -> Vec<isize> {
+    let mut h: Vec<isize> = Vec::new();
+    for y in sky.y() {
+        if sky
+            .x()
+            .all(|x| sky.read(x, y).unwrap_or_default() == Pixel::Space)
+        {
+            h.push(y);
+        }
+    }
+    h
+}
+
+fn basic(v: &[isize], h: &[isize], from: (isize, isize), to: (isize, isize)) -> isize {
+    distance(v, h, from, to, 1)
+}
+
+/// Distance between two galaxies, when each empty row or column counts f extra times
+pub fn distance(
+    v: &[isize],
+    h: &[isize],
+    from: (isize, isize),
+    to: (isize, isize),
+    f: usize,
+) -> isize {
+    let x = (to.0 - from.0).abs();
+    let y = (to.1 - from.1).abs();
+    let vert = v
+        .iter()
+        .filter(|&&x| (x > to.0 && x < from.0) || (x > from.0 && x < to.0))
+        .count()
+        * f;
+    let horz = h
+        .iter()
+        .filter(|&&y| (y > to.1 && y < from.1) || (y > from.1 && y < to.1))
+        .count()
+        * f;
+    let extra = vert + horz;
+    x + y + (extra as isize)
+}
+
+/// An image of the sky
+#[derive(Clone, Debug)]
+pub struct Image {
+    /// Columns with no galaxies
+    pub vertical: Vec<isize>,
+    /// Rows with no galaxies
+    pub horizontal: Vec<isize>,
+    pub galaxies: Vec<(isize, isize)>,
+}
+
+pub fn parse(ctxt: &Contents) -> Result<Image, ParseError> {
+    let sky: Pixels = ctxt.grid()?;
+    Ok(Image {
+        vertical: check_vertical(&sky),
+        horizontal: check_horizontal(&sky),
+        galaxies: sky.find(|p| p == Pixel::Galaxy),
+    })
+}
+
+use crate::combinatorics::Combinations;
+
+// Sum of the distances between every pair of galaxies, as measured by the distance function
+fn pairs<D>(image: &Image, distance: D) -> isize
+where
+    D: Fn(&[isize], &[isize], (isize, isize), (isize, isize)) -> isize,
+{
+    let Image {
+        vertical: v,
+        horizontal: h,
+        galaxies,
+    } = image;
+    Combinations::new(galaxies, 2)
+        .map(|pair| distance(v, h, pair[0], pair[1]))
+        .sum()
+}
+
+pub fn a(image: &Image) -> isize {
+    pairs(image, basic)
+}
+
+pub fn b(image: &Image) -> isize {
+    pairs(image, |v, h, from, to| distance(v, h, from, to, 999_999))
+}
@@ -1,6 +1,6 @@
-use sky::readfile;
+use crate::{Contents, ParseError};
 
-type Number = u64;
+pub type Number = u64;
 type Bits = u128;
 
 const OP: u8 = b'.';
@@ -121,19 +121,63 @@ impl Arrangement {
     }
 }
 
-fn ordinary(line: &str) -> Number {
-    let (springs, nums) = line
-        .split_once(' ')
-        .expect("Each line should have a space in it");
-    let nums: Vec<Number> = nums
-        .split(',')
-        .map(|n| n.parse::<Number>().expect("Should be an integer"))
-        .collect();
+/// One line of the condition records, the springs and the sizes of each group of damaged springs
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub springs: String,
+    pub groups: Vec<Number>,
+}
+
+use std::str::FromStr;
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let Some((springs, nums)) = line.split_once(' ') else {
+            return Err(ParseError::new(line, line, "springs then groups"));
+        };
+        let mut odd = springs.char_indices();
+        if let Some((n, ch)) = odd.find(|&(_, ch)| !matches!(ch, '.' | '#' | '?')) {
+            let found = &springs[n..n + ch.len_utf8()];
+            return Err(ParseError::new(line, found, "a spring, one of .#?"));
+        }
+        let groups = nums
+            .split(',')
+            .map(|n| {
+                n.parse()
+                    .map_err(|_| ParseError::new(line, n, "a group size"))
+            })
+            .collect::<Result<Vec<Number>, ParseError>>()?;
+        Ok(Self {
+            springs: String::from(springs),
+            groups,
+        })
+    }
+}
+
+impl Record {
+    /// The record as it really is, five copies of the springs and the groups
+    pub fn unfold(&self) -> Self {
+        let springs = &self.springs;
+        Self {
+            springs: format!("{springs}?{springs}?{springs}?{springs}?{springs}"),
+            groups: self.groups.repeat(5),
+        }
+    }
+}
+
+pub fn parse(ctxt: &Contents) -> Result<Vec<Record>, ParseError> {
+    ctxt.parsed()
+}
+
+/// Count possible arrangements by trying every one of them
+pub fn ordinary(record: &Record) -> Number {
+    let nums = &record.groups;
     let mut v = Vec::new();
-    v.push(Arrangement::new(springs));
+    v.push(Arrangement::new(&record.springs));
     let mut count = 0;
     while let Some(arr) = v.pop() {
-        match arr.step(&nums) {
+        match arr.step(nums) {
             Outcome::Fail => {}
             Outcome::Success => count += 1,
             Outcome::Onward(arr) => {
@@ -173,41 +217,21 @@ fn working(arr: Arrangement, correct: &[Number], memo: &mut Memo) -> Number {
     }
 }
 
-fn revised(line: &str) -> Number {
-    let (springs, nums) = line
-        .split_once(' ')
-        .expect("Each line should have a space in it");
-    let nums: Vec<Number> = nums
-        .split(',')
-        .map(|n| n.parse::<Number>().expect("Should be an integer"))
-        .collect();
-
-    let mut correct = nums.clone();
-    for _ in 0..4 {
-        correct.extend_from_slice(&nums);
-    }
-
-    let more = format!("{springs}?{springs}?{springs}?{springs}?{springs}");
+/// Count possible arrangements, remembering how many there were from each partial arrangement
+pub fn revised(record: &Record) -> Number {
     let mut memo = HashMap::new();
-    working(Arrangement::new(&more), &correct, &mut memo)
+    working(Arrangement::new(&record.springs), &record.groups, &mut memo)
 }
 
-pub fn a() {
-    let ctxt = readfile("12");
-    let mut sum = 0;
-    for line in ctxt.lines() {
-        let count = ordinary(line);
-        sum += count;
-    }
-    println!("Sum of counts of different arrangements is: {sum}");
+pub fn a(records: &[Record]) -> Number {
+    records.iter().map(ordinary).sum()
 }
 
-pub fn b() {
-    let ctxt = readfile("12");
-    let mut sum = 0;
-    for line in ctxt.lines() {
-        let count = revised(line);
-        sum += count;
-    }
-    println!("After unfolding, now sum of counts is: {sum}");
+/// Part a, but counting arrangements the way part b does
+pub fn a_memo(records: &[Record]) -> Number {
+    records.iter().map(revised).sum()
+}
+
+pub fn b(records: &[Record]) -> Number {
+    records.iter().map(|record| revised(&record.unfold())).sum()
 }
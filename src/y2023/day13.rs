@@ -0,0 +1,65 @@
+use crate::map::Map;
+use crate::{Contents, ParseError};
+
+pub type Number = u32;
+
+pub type Pattern = Map<char>;
+
+/// Rows above a horizontal line of reflection, where the reflected rows differ from the
+/// pattern in exactly this many places
+fn reflection(pattern: &Pattern, smudges: usize) -> Option<Number> {
+    let &bottom = pattern.y().end();
+    (1..=bottom)
+        .find(|&mirror| {
+            let differences: usize = (0..mirror)
+                .rev()
+                .zip(mirror..=bottom)
+                .map(|(top, bottom)| {
+                    pattern
+                        .row(top)
+                        .zip(pattern.row(bottom))
+                        .filter(|(t, b)| t != b)
+                        .count()
+                })
+                .sum();
+            differences == smudges
+        })
+        .map(|mirror| mirror as Number)
+}
+
+/// Summary of the pattern's line of reflection, with this many smudges
+fn summary(pattern: &Pattern, smudges: usize) -> Number {
+    if let Some(rows) = reflection(pattern, smudges) {
+        return rows * 100;
+    }
+    reflection(&pattern.transpose(), smudges).unwrap_or(0)
+}
+
+/// Summary of the pattern's line of reflection
+pub fn assess(pattern: &Pattern) -> Number {
+    summary(pattern, 0)
+}
+
+/// Each pattern as a Map
+pub fn parse(ctxt: &Contents) -> Result<Vec<Pattern>, ParseError> {
+    ctxt.sections()
+        .map(|section| {
+            section
+                .parse()
+                .map_err(|e: ParseError| e.starting_at(ctxt.line_of(section)))
+        })
+        .collect()
+}
+
+pub fn a(patterns: &[Pattern]) -> Number {
+    patterns.iter().map(assess).sum()
+}
+
+/// Summary of the pattern's line of reflection once its smudge is fixed
+pub fn reassess(pattern: &Pattern) -> Number {
+    summary(pattern, 1)
+}
+
+pub fn b(patterns: &[Pattern]) -> Number {
+    patterns.iter().map(reassess).sum()
+}
@@ -0,0 +1,105 @@
+use crate::map::Map;
+use crate::{Contents, ParseError};
+
+type Number = usize;
+
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+pub enum Rock {
+    #[default]
+    Empty,
+    Round,
+    Cube,
+}
+
+impl TryFrom<char> for Rock {
+    type Error = ParseError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        Ok(match ch {
+            '.' => Self::Empty,
+            'O' => Self::Round,
+            '#' => Self::Cube,
+            _ => return Err(ParseError::char(ch, "a rock, one of .O#")),
+        })
+    }
+}
+
+pub type Dish = Map<Rock>;
+
+use std::fmt::{Display, Formatter};
+
+impl Display for Rock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Empty => f.write_str("."),
+            Self::Round => f.write_str("O"),
+            Self::Cube => f.write_str("#"),
+        }
+    }
+}
+
+pub fn tilt_north(mut dish: Dish) -> Dish {
+    for x in dish.x() {
+        let mut stop: isize = 0;
+
+        for y in dish.y() {
+            match dish.read(x, y).unwrap_or_default() {
+                Rock::Empty => {}
+                Rock::Round => {
+                    if stop < y {
+                        // Roll
+                        dish.write(x, stop, Rock::Round);
+                        dish.write(x, y, Rock::Empty);
+                    }
+                    stop += 1;
+                }
+                Rock::Cube => {
+                    stop = y + 1;
+                }
+            }
+        }
+    }
+    dish
+}
+
+pub fn tilt_west(dish: Dish) -> Dish {
+    tilt_north(dish.transpose()).transpose()
+}
+
+pub fn tilt_south(dish: Dish) -> Dish {
+    tilt_north(dish.flip_v()).flip_v()
+}
+
+pub fn tilt_east(dish: Dish) -> Dish {
+    tilt_north(dish.transpose().flip_v()).flip_v().transpose()
+}
+
+/// One spin cycle, tilting north, then west, then south, then east
+pub fn cycle(dish: Dish) -> Dish {
+    tilt_east(tilt_south(tilt_west(tilt_north(dish))))
+}
+
+/// Total load on the north support beams
+pub fn load(dish: Dish) -> isize {
+    let height = dish.y().end() + 1;
+    dish.find(|r| r == Rock::Round)
+        .iter()
+        .map(|(_, y)| height - y)
+        .sum()
+}
+
+pub fn parse(ctxt: &Contents) -> Result<Dish, ParseError> {
+    ctxt.grid()
+}
+
+pub fn a(dish: &Dish) -> isize {
+    let dish = tilt_north(dish.clone());
+    load(dish)
+}
+
+const BILLION: Number = 1_000_000_000;
+
+pub fn b(dish: &Dish) -> isize {
+    let dish = crate::nth(dish.clone(), cycle, BILLION);
+    load(dish)
+}
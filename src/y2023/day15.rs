@@ -0,0 +1,139 @@
+use crate::{Contents, ParseError};
+
+pub type Number = u32;
+
+/// The Holiday ASCII String Helper algorithm
+pub fn hash(s: &str) -> Number {
+    let mut answer = 0;
+    for c in s.chars() {
+        let code = c as u32;
+        answer += code;
+        answer *= 17;
+        answer %= 256;
+    }
+    answer
+}
+
+/// What a step does to the lens with its label
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    Remove,
+    Insert(Number),
+}
+
+/// One step of the initialization sequence, both as written and as what it does
+#[derive(Copy, Clone, Debug)]
+pub struct Step<'t> {
+    pub text: &'t str,
+    pub label: &'t str,
+    pub operation: Operation,
+}
+
+fn step<'t>(sequence: &str, text: &'t str) -> Result<Step<'t>, ParseError> {
+    if let Some(label) = text.strip_suffix('-') {
+        let operation = Operation::Remove;
+        return Ok(Step {
+            text,
+            label,
+            operation,
+        });
+    }
+    let Some((label, focal)) = text.split_once('=') else {
+        return Err(ParseError::new(sequence, text, "a label then - or ="));
+    };
+    let focal = focal
+        .parse()
+        .map_err(|_| ParseError::new(sequence, focal, "a focal length"))?;
+    let operation = Operation::Insert(focal);
+    Ok(Step {
+        text,
+        label,
+        operation,
+    })
+}
+
+/// Each step of the initialization sequence
+pub fn parse(ctxt: &Contents) -> Result<Vec<Step<'_>>, ParseError> {
+    let sequence = ctxt.value();
+    let line = ctxt.line_of(sequence);
+    sequence
+        .split(',')
+        .map(|text| step(sequence, text).map_err(|e| e.line(line)))
+        .collect()
+}
+
+pub fn a(steps: &[Step]) -> Number {
+    steps.iter().map(|step| hash(step.text)).sum()
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lens {
+    pub label: String,
+    pub focal: Number,
+}
+
+impl Lens {
+    pub fn new(label: &str, focal: Number) -> Self {
+        let label = String::from(label);
+        Self { label, focal }
+    }
+}
+
+/// The boxes of lenses
+#[derive(Clone, Debug)]
+pub struct Array {
+    pub boxes: [Vec<Lens>; 256],
+}
+
+impl Array {
+    pub fn new() -> Self {
+        let boxes = [(); 256].map(|_| Vec::new());
+        Self { boxes }
+    }
+
+    pub fn remove(&mut self, bx: Number, label: &str) {
+        self.boxes[bx as usize].retain(|lens| lens.label != label);
+    }
+
+    pub fn add_or_replace(&mut self, bx: Number, label: &str, focal: Number) {
+        let new = Lens::new(label, focal);
+        for lens in self.boxes[bx as usize].iter_mut() {
+            if lens.label == label {
+                *lens = new;
+                return;
+            }
+        }
+        self.boxes[bx as usize].push(new);
+    }
+
+    /// Total focusing power of the lenses
+    pub fn power(&self) -> Number {
+        let mut total = 0;
+        for (k, bx) in self.boxes.iter().enumerate() {
+            for (n, lens) in bx.iter().enumerate() {
+                let a = (k + 1) as Number;
+                let b = (n + 1) as Number;
+                total += a * b * lens.focal;
+            }
+        }
+        total
+    }
+}
+
+impl Default for Array {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn b(steps: &[Step]) -> Number {
+    let mut array = Array::new();
+    for step in steps {
+        let bx = hash(step.label);
+        match step.operation {
+            Operation::Remove => array.remove(bx, step.label),
+            Operation::Insert(focal) => array.add_or_replace(bx, step.label, focal),
+        }
+    }
+    array.power()
+}
@@ -1,9 +1,9 @@
-use sky::map::Map;
-use sky::readfile;
+use crate::map::Map;
+use crate::{Contents, ParseError};
 use std::collections::HashSet;
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
-enum Element {
+pub enum Element {
     #[default]
     Empty,
     MirrorLeft,
@@ -12,20 +12,22 @@ enum Element {
     SplitHoriz,
 }
 
-impl From<char> for Element {
-    fn from(ch: char) -> Self {
-        match ch {
+impl TryFrom<char> for Element {
+    type Error = ParseError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        Ok(match ch {
             '.' => Self::Empty,
             '/' => Self::MirrorLeft,
             '\\' => Self::MirrorRight,
             '-' => Self::SplitHoriz,
             '|' => Self::SplitVert,
-            _ => panic!("Impossible symbol {ch}"),
-        }
+            _ => return Err(ParseError::char(ch, r"a mirror or splitter, one of ./\-|")),
+        })
     }
 }
 
-type Contraption = Map<Element>;
+pub type Contraption = Map<Element>;
 
 use std::fmt::{Display, Formatter};
 
@@ -42,7 +44,7 @@ impl Display for Element {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-enum Direction {
+pub enum Direction {
     Up,
     Down,
     Left,
@@ -50,10 +52,10 @@ enum Direction {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-struct Beam {
-    x: isize,
-    y: isize,
-    direction: Direction,
+pub struct Beam {
+    pub x: isize,
+    pub y: isize,
+    pub direction: Direction,
 }
 
 impl Beam {
@@ -131,7 +133,8 @@ fn basic(input: &Contraption) -> usize {
     )
 }
 
-fn energized(input: &Contraption, start: Beam) -> usize {
+/// How many tiles are energized by a beam entering from start
+pub fn energized(input: &Contraption, start: Beam) -> usize {
     let mut done: HashSet<Beam> = HashSet::new();
     let mut out: HashSet<(isize, isize)> = HashSet::new();
     let mut todo: Vec<Beam> = Vec::new();
@@ -171,23 +174,21 @@ fn energized(input: &Contraption, start: Beam) -> usize {
     out.len()
 }
 
-pub fn a() {
-    let ctxt = readfile("16");
-    let map: Contraption = ctxt.value().parse().unwrap();
-    let count = basic(&map);
-    println!("{count} tiles end up energized");
+pub fn parse(ctxt: &Contents) -> Result<Contraption, ParseError> {
+    ctxt.grid()
 }
 
-pub fn b() {
-    let ctxt = readfile("16");
-    let map: Contraption = ctxt.value().parse().unwrap();
+pub fn a(map: &Contraption) -> usize {
+    basic(map)
+}
 
+pub fn b(map: &Contraption) -> usize {
     let &right = map.x().end();
     let &bottom = map.y().end();
     let mut best = 0;
     for x in map.x() {
         let count = energized(
-            &map,
+            map,
             Beam {
                 x,
                 y: 0,
@@ -198,7 +199,7 @@ pub fn b() {
             best = count;
         }
         let count = energized(
-            &map,
+            map,
             Beam {
                 x,
                 y: bottom,
@@ -211,7 +212,7 @@ pub fn b() {
     }
     for y in map.y() {
         let count = energized(
-            &map,
+            map,
             Beam {
                 x: 0,
                 y,
@@ -222,7 +223,7 @@ pub fn b() {
             best = count;
         }
         let count = energized(
-            &map,
+            map,
             Beam {
                 x: right,
                 y,
@@ -234,5 +235,5 @@ pub fn b() {
         }
     }
 
-    println!("Most tiles energized is: {best}");
+    best
 }
@@ -1,25 +1,27 @@
-use sky::map::Map;
-use sky::readfile;
+use crate::map::Map;
+use crate::{Contents, ParseError};
 
-type Number = u32;
+pub type Number = u32;
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
-struct Block {
-    loss: u8,
+pub struct Block {
+    pub loss: u8,
 }
 
-impl From<char> for Block {
-    fn from(ch: char) -> Self {
-        match ch {
+impl TryFrom<char> for Block {
+    type Error = ParseError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        Ok(match ch {
             d @ '1'..='9' => Self {
                 loss: d.to_digit(10).unwrap().try_into().unwrap(),
             },
-            _ => panic!("Impossible symbol {ch}"),
-        }
+            _ => return Err(ParseError::char(ch, "a heat loss from 1 to 9")),
+        })
     }
 }
 
-type Factory = Map<Block>;
+pub type Factory = Map<Block>;
 
 use std::fmt::{Display, Formatter};
 
@@ -30,7 +32,7 @@ impl Display for Block {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 enum Direction {
     Horiz,
     Vert,
@@ -79,7 +81,8 @@ fn maybe_update(paths: &mut Map<Best>, next: &mut Vec<Step>, step: Step, loss: N
     next.push((x, y, direction));
 }
 
-fn least(map: &Factory) -> Number {
+/// Least heat loss from top left to bottom right, moving at most three blocks in a line
+pub fn least(map: &Factory) -> Number {
     let &right = map.x().end();
     let &bottom = map.y().end();
 
@@ -159,14 +162,16 @@ fn least(map: &Factory) -> Number {
     dest.best_of_the_best().unwrap()
 }
 
-pub fn a() {
-    let ctxt = readfile("17");
-    let map: Factory = ctxt.value().parse().unwrap();
-    let best = least(&map);
-    println!("Least loss is: {best}");
+pub fn parse(ctxt: &Contents) -> Result<Factory, ParseError> {
+    ctxt.grid()
+}
+
+pub fn a(map: &Factory) -> Number {
+    least(map)
 }
 
-fn ultra(map: &Factory) -> Number {
+/// Least heat loss for an ultra crucible, moving at least four and at most ten blocks in a line
+pub fn ultra(map: &Factory) -> Number {
     let &right = map.x().end();
     let &bottom = map.y().end();
 
@@ -258,9 +263,95 @@ fn ultra(map: &Factory) -> Number {
     dest.best_of_the_best().unwrap()
 }
 
-pub fn b() {
-    let ctxt = readfile("17");
-    let map: Factory = ctxt.value().parse().unwrap();
-    let best = ultra(&map);
-    println!("Least loss with ultra crucible is: {best}");
+pub fn b(map: &Factory) -> Number {
+    ultra(map)
+}
+
+/// The rules for moving a crucible across the factory
+struct Rules<'a> {
+    map: &'a Factory,
+    /// How many blocks the crucible may move in a line before it must turn
+    moves: RangeInclusive<isize>,
+}
+
+/// Where a crucible is and which way it can move next, either way at the start
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct Crucible {
+    x: isize,
+    y: isize,
+    heading: Option<Direction>,
+}
+
+use crate::State;
+use std::ops::RangeInclusive;
+
+impl State<Rules<'_>> for Crucible {
+    fn describe(&self, _rules: &Rules) -> String {
+        format!("{} {} {:?}", self.x, self.y, self.heading)
+    }
+
+    fn next(&self, rules: &Rules) -> Vec<Self> {
+        self.next_weighted(rules)
+            .into_iter()
+            .map(|(crucible, _)| crucible)
+            .collect()
+    }
+
+    fn next_weighted(&self, rules: &Rules) -> Vec<(Self, usize)> {
+        let mut next = Vec::new();
+        let mut go = |dx: isize, dy: isize, heading: Direction| {
+            let mut loss = 0;
+            for n in 1..=*rules.moves.end() {
+                let (x, y) = (self.x + dx * n, self.y + dy * n);
+                if !rules.map.contains(x, y) {
+                    break;
+                }
+                loss += rules.map.read(x, y).expect("Map out of bounds").loss as usize;
+                if rules.moves.contains(&n) {
+                    let heading = Some(heading);
+                    next.push((Crucible { x, y, heading }, loss));
+                }
+            }
+        };
+        if self.heading != Some(Direction::Vert) {
+            go(1, 0, Direction::Vert);
+            go(-1, 0, Direction::Vert);
+        }
+        if self.heading != Some(Direction::Horiz) {
+            go(0, 1, Direction::Horiz);
+            go(0, -1, Direction::Horiz);
+        }
+        next
+    }
+}
+
+/// Least heat loss from top left to bottom right, searching with the State trait
+fn cheapest(map: &Factory, moves: RangeInclusive<isize>) -> Number {
+    let &right = map.x().end();
+    let &bottom = map.y().end();
+    let rules = Rules { map, moves };
+    let start = Crucible {
+        x: 0,
+        y: 0,
+        heading: None,
+    };
+    let Some((loss, _)) = Crucible::cheapest_with_heuristic(
+        start,
+        |c| c.x == right && c.y == bottom,
+        |c| (right - c.x + bottom - c.y) as usize,
+        &rules,
+    ) else {
+        panic!("Should have identified a best route");
+    };
+    loss as Number
+}
+
+/// Part a, but searching for the least loss with A*
+pub fn a_astar(map: &Factory) -> Number {
+    cheapest(map, 1..=3)
+}
+
+/// Part b, but searching for the least loss with A*
+pub fn b_astar(map: &Factory) -> Number {
+    cheapest(map, 4..=10)
 }
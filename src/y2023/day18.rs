@@ -0,0 +1,219 @@
+use crate::map::{Map, Region};
+use crate::{Contents, ParseError};
+
+pub type Number = i64;
+
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+enum Cube {
+    #[default]
+    Ground,
+    Trench,
+}
+
+type Lagoon = Map<Cube>;
+
+use std::fmt::{Debug, Formatter};
+
+impl Debug for Cube {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Ground => f.write_str("."),
+            Self::Trench => f.write_str("#"),
+        }
+    }
+}
+
+// Dig out everything enclosed by the trench
+fn dig(lagoon: &mut Lagoon) {
+    let regions = lagoon.enclosed(|c| c == Cube::Trench);
+    for (x, y) in regions.find(|r| r == Region::Enclosed) {
+        lagoon.write(x, y, Cube::Trench);
+    }
+}
+
+/// One step of the dig plan, a direction and how far to dig in that direction
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Step {
+    pub dx: isize,
+    pub dy: isize,
+    pub distance: isize,
+}
+
+fn step(line: &str) -> Result<Step, ParseError> {
+    let mut fields = line.split(' ');
+    let (Some(dir), Some(dist), Some(_)) = (fields.next(), fields.next(), fields.next()) else {
+        return Err(ParseError::new(
+            line,
+            line,
+            "direction, distance and colour",
+        ));
+    };
+    let distance = dist
+        .parse()
+        .map_err(|_| ParseError::new(line, dist, "a distance"))?;
+    let (dx, dy) = match dir {
+        "R" => (1, 0),
+        "D" => (0, 1),
+        "L" => (-1, 0),
+        "U" => (0, -1),
+        _ => return Err(ParseError::new(line, dir, "a direction, one of UDLR")),
+    };
+    Ok(Step { dx, dy, distance })
+}
+
+fn hex(line: &str) -> Result<Step, ParseError> {
+    let Some((_, colour)) = line.rsplit_once(' ') else {
+        return Err(ParseError::new(
+            line,
+            line,
+            "direction, distance and colour",
+        ));
+    };
+    let code = colour.strip_prefix("(#").and_then(|c| c.strip_suffix(')'));
+    let Some(code) = code.filter(|code| code.len() == 6 && code.is_ascii()) else {
+        return Err(ParseError::new(line, colour, "a colour like (#70c710)"));
+    };
+    let (dist, dir) = code.split_at(5);
+    let distance = isize::from_str_radix(dist, 16)
+        .map_err(|_| ParseError::new(line, dist, "a hexadecimal distance"))?;
+    let (dx, dy) = match dir {
+        "0" => (1, 0),
+        "1" => (0, 1),
+        "2" => (-1, 0),
+        "3" => (0, -1),
+        _ => return Err(ParseError::new(line, dir, "a direction, one of 0123")),
+    };
+    Ok(Step { dx, dy, distance })
+}
+
+/// The dig plan as it first appears
+pub fn parse(ctxt: &Contents) -> Result<Vec<Step>, ParseError> {
+    ctxt.lines()
+        .enumerate()
+        .map(|(n, line)| step(line).map_err(|e| e.line(n + 1)))
+        .collect()
+}
+
+/// The dig plan hidden in the hexadecimal colour codes
+pub fn parse_hex(ctxt: &Contents) -> Result<Vec<Step>, ParseError> {
+    ctxt.lines()
+        .enumerate()
+        .map(|(n, line)| hex(line).map_err(|e| e.line(n + 1)))
+        .collect()
+}
+
+/// Size of the lagoon, digging out every cube of it
+pub fn flooded(steps: &[Step]) -> usize {
+    let mut lagoon: Lagoon = Map::new();
+    let mut x = 0;
+    let mut y = 0;
+    lagoon.write(x, y, Cube::Trench);
+    for step in steps {
+        for _ in 0..step.distance {
+            x += step.dx;
+            y += step.dy;
+            lagoon.write(x, y, Cube::Trench);
+        }
+    }
+    dig(&mut lagoon);
+    lagoon.count(|&&x| x == Cube::Trench)
+}
+
+pub fn a(steps: &[Step]) -> usize {
+    flooded(steps)
+}
+
+fn lookup(horiz: &[isize], vert: &[isize], x: isize, y: isize) -> (isize, isize) {
+    let Ok(x) = horiz.binary_search(&x) else {
+        panic!("Couldn't find {x} in {horiz:?}");
+    };
+    let Ok(y) = vert.binary_search(&y) else {
+        panic!("Couldn't find {y} in {vert:?}");
+    };
+    (2 * x as isize, 2 * y as isize)
+}
+
+use std::cmp::Ordering;
+fn draw(lagoon: &mut Lagoon, from: (isize, isize), to: (isize, isize)) {
+    let dx = match from.0.cmp(&to.0) {
+        Ordering::Less => 1,
+        Ordering::Greater => -1,
+        Ordering::Equal => 0,
+    };
+    let dy = match from.1.cmp(&to.1) {
+        Ordering::Less => 1,
+        Ordering::Greater => -1,
+        Ordering::Equal => 0,
+    };
+
+    let mut x = from.0;
+    let mut y = from.1;
+    while x != to.0 || y != to.1 {
+        lagoon.write(x, y, Cube::Trench);
+        x += dx;
+        y += dy;
+    }
+}
+
+fn size(lagoon: &Lagoon, horiz: &[isize], vert: &[isize]) -> Number {
+    let mut n = 0;
+    for (x, y) in lagoon.find(|c| c == Cube::Trench) {
+        // The odd numbers represent potentially large spreads
+
+        let width = if x & 1 == 1 {
+            let left = (x / 2) as usize;
+            let right = (x / 2 + 1) as usize;
+            (horiz[right] - horiz[left] - 1) as Number
+        } else {
+            1
+        };
+        let height = if y & 1 == 1 {
+            let top = (y / 2) as usize;
+            let bottom = (y / 2 + 1) as usize;
+            (vert[bottom] - vert[top] - 1) as Number
+        } else {
+            1
+        };
+
+        n += width * height;
+    }
+    n
+}
+
+/// Size of the lagoon, compressing the coordinates so that only the corners are dug out
+pub fn compressed(steps: &[Step]) -> Number {
+    let mut x = 0;
+    let mut y = 0;
+    let mut coords = Vec::new();
+
+    for step in steps {
+        x += step.dx * step.distance;
+        y += step.dy * step.distance;
+        coords.push((x, y));
+    }
+    let mut horiz: Vec<isize> = coords.iter().map(|&(x, _)| x).collect();
+    horiz.sort();
+    horiz.dedup();
+    let mut vert: Vec<isize> = coords.iter().map(|&(_, y)| y).collect();
+    vert.sort();
+    vert.dedup();
+
+    let mut lagoon: Lagoon = Map::new();
+    let mut from: (isize, isize) = lookup(&horiz, &vert, 0, 0);
+    for (x, y) in coords {
+        let to = lookup(&horiz, &vert, x, y);
+        draw(&mut lagoon, from, to);
+        from = to;
+    }
+    dig(&mut lagoon);
+    size(&lagoon, &horiz, &vert)
+}
+
+/// Part a, but measuring the lagoon the way part b does
+pub fn a_compressed(steps: &[Step]) -> Number {
+    compressed(steps)
+}
+
+pub fn b(steps: &[Step]) -> Number {
+    compressed(steps)
+}
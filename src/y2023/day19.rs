@@ -1,16 +1,16 @@
-use sky::readfile;
+use crate::{Contents, ParseError};
 use std::collections::HashMap;
 
-type Number = u64;
+pub type Number = u64;
 
 #[derive(Copy, Clone, Debug)]
-enum MoreOrLess {
+pub enum MoreOrLess {
     Less,
     More,
 }
 
 #[derive(Copy, Clone, Debug)]
-enum Xmas {
+pub enum Xmas {
     X,
     M,
     A,
@@ -18,11 +18,11 @@ enum Xmas {
 }
 
 #[derive(Clone, Debug)]
-struct Rule {
-    target: String,
-    kind: MoreOrLess,
-    letter: Xmas,
-    num: Number,
+pub struct Rule {
+    pub target: String,
+    pub kind: MoreOrLess,
+    pub letter: Xmas,
+    pub num: Number,
 }
 
 impl Rule {
@@ -41,11 +41,11 @@ impl Rule {
 }
 
 #[derive(Copy, Clone, Debug)]
-struct Ratings {
-    x: Number,
-    m: Number,
-    a: Number,
-    s: Number,
+pub struct Ratings {
+    pub x: Number,
+    pub m: Number,
+    pub a: Number,
+    pub s: Number,
 }
 
 impl std::ops::Index<Xmas> for Ratings {
@@ -62,121 +62,91 @@ impl std::ops::Index<Xmas> for Ratings {
 }
 
 impl Ratings {
-    fn total(&self) -> Number {
+    pub fn total(&self) -> Number {
         self.x + self.m + self.a + self.s
     }
 }
 
 use std::str::FromStr;
 impl FromStr for Ratings {
-    type Err = &'static str;
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let Some((x, rest)) = s.split_once(",m=") else {
-            return Err("No m rating");
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let rating = |n: &str| n.parse().map_err(|_| ParseError::new(line, n, "a rating"));
+        let Some((x, rest)) = line.split_once(",m=") else {
+            return Err(ParseError::new(line, line, ",m="));
         };
         let Some((m, rest)) = rest.split_once(",a=") else {
-            return Err("No a rating");
+            return Err(ParseError::new(line, rest, ",a="));
         };
         let Some((a, s)) = rest.split_once(",s=") else {
-            return Err("No s rating");
+            return Err(ParseError::new(line, rest, ",s="));
         };
         let Some(x) = x.strip_prefix("{x=") else {
-            return Err("Does not start with {x=");
-        };
-        let Ok(x): Result<Number, _> = x.parse() else {
-            return Err("Rating x is not an integer");
-        };
-        let Ok(m): Result<Number, _> = m.parse() else {
-            return Err("Rating m is not an integer");
-        };
-        let Ok(a): Result<Number, _> = a.parse() else {
-            return Err("Rating a is not an integer");
+            return Err(ParseError::new(line, x, "{x="));
         };
         let Some(s) = s.strip_suffix('}') else {
-            return Err("Does not end with }");
+            return Err(ParseError::end(line, "}"));
         };
-        let Ok(s): Result<Number, _> = s.parse() else {
-            return Err("Rating s is not an integer");
-        };
-        Ok(Ratings { x, m, a, s })
+        Ok(Ratings {
+            x: rating(x)?,
+            m: rating(m)?,
+            a: rating(a)?,
+            s: rating(s)?,
+        })
     }
 }
 
-fn parse(s: &str) -> (&str, Vec<Rule>) {
-    let (name, rest) = s.split_once('{').expect("Workflow should have braces");
+// A rule with a condition, like a<2006:qkq
+fn rule(s: &str, condition: &str, target: &str) -> Result<Rule, ParseError> {
+    let mut chars = condition.chars();
+    let letter = match chars.next() {
+        Some('x') => Xmas::X,
+        Some('m') => Xmas::M,
+        Some('a') => Xmas::A,
+        Some('s') => Xmas::S,
+        _ => return Err(ParseError::new(s, condition, "a rating, one of xmas")),
+    };
+    let kind = match chars.next() {
+        Some('<') => MoreOrLess::Less,
+        Some('>') => MoreOrLess::More,
+        _ => return Err(ParseError::new(s, &condition[1..], "< or >")),
+    };
+    let num = &condition[2..];
+    let num = num
+        .parse()
+        .map_err(|_| ParseError::new(s, num, "a number"))?;
+    Ok(Rule {
+        target: String::from(target),
+        kind,
+        letter,
+        num,
+    })
+}
+
+/// A workflow's name and its rules
+pub fn workflow(s: &str) -> Result<(&str, Vec<Rule>), ParseError> {
+    let Some((name, rest)) = s.split_once('{') else {
+        return Err(ParseError::new(s, s, "a name then rules in braces"));
+    };
+    let Some(rest) = rest.strip_suffix('}') else {
+        return Err(ParseError::end(s, "}"));
+    };
     let mut rules = Vec::new();
-    let rest = rest
-        .strip_suffix('}')
-        .expect("Workflow should have braces surrounding rules");
-    for rule in rest.split(',') {
-        let Some((condition, target)) = rule.split_once(':') else {
-            rules.push(Rule::unconditional(String::from(rule)));
-            continue;
-        };
-        let (what, num) = condition.split_at(2);
-        let num: Number = num.parse().expect("Numerics should be integers");
-        let target = String::from(target);
-        let rule = match what {
-            "x<" => Rule {
-                target,
-                kind: MoreOrLess::Less,
-                letter: Xmas::X,
-                num,
-            },
-            "x>" => Rule {
-                target,
-                kind: MoreOrLess::More,
-                letter: Xmas::X,
-                num,
-            },
-            "m<" => Rule {
-                target,
-                kind: MoreOrLess::Less,
-                letter: Xmas::M,
-                num,
-            },
-            "m>" => Rule {
-                target,
-                kind: MoreOrLess::More,
-                letter: Xmas::M,
-                num,
-            },
-            "a<" => Rule {
-                target,
-                kind: MoreOrLess::Less,
-                letter: Xmas::A,
-                num,
-            },
-            "a>" => Rule {
-                target,
-                kind: MoreOrLess::More,
-                letter: Xmas::A,
-                num,
-            },
-            "s<" => Rule {
-                target,
-                kind: MoreOrLess::Less,
-                letter: Xmas::S,
-                num,
-            },
-            "s>" => Rule {
-                target,
-                kind: MoreOrLess::More,
-                letter: Xmas::S,
-                num,
-            },
-            _ => panic!("Impossible rule {rule}"),
-        };
-        rules.push(rule);
+    for r in rest.split(',') {
+        match r.split_once(':') {
+            Some((condition, target)) => rules.push(rule(s, condition, target)?),
+            None => rules.push(Rule::unconditional(String::from(r))),
+        }
     }
-
-    (name, rules)
+    Ok((name, rules))
 }
 
-type Rules<'t> = HashMap<&'t str, Vec<Rule>>;
+/// Every workflow, by name
+pub type Rules<'t> = HashMap<&'t str, Vec<Rule>>;
 
-fn accept(rules: &Rules, part: Ratings) -> bool {
+/// Whether the workflows accept this part
+pub fn accept(rules: &Rules, part: Ratings) -> bool {
     let mut name = "in";
     loop {
         let conditions = rules.get(name).expect("Named rule should be in rules list");
@@ -354,7 +324,8 @@ impl Combs {
 
 type Attempt<'a> = (&'a str, Combs);
 
-fn combinations(rules: &Rules) -> Number {
+/// How many distinct combinations of ratings would be accepted
+pub fn combinations(rules: &Rules) -> Number {
     let mut accepted = 0;
     let mut todo: Vec<Attempt> = vec![("in", Combs::new())];
 
@@ -383,43 +354,38 @@ fn combinations(rules: &Rules) -> Number {
     accepted
 }
 
-pub fn a() {
-    let ctxt = readfile("19");
-    let mut lines = ctxt.lines();
-    let mut rules: Rules = HashMap::new();
-
-    for line in lines.by_ref() {
-        if line.is_empty() {
-            break;
-        }
-        let (id, v) = parse(line);
-        rules.insert(id, v);
-    }
-
-    let mut sum = 0;
-    for line in lines {
-        let part: Ratings = line
-            .parse()
-            .expect("Should be ratings below the blank line");
-        if accept(&rules, part) {
-            sum += part.total();
+/// The workflows, then the ratings for each part
+pub fn parse(ctxt: &Contents) -> Result<(Rules<'_>, Vec<Ratings>), ParseError> {
+    let mut sections = ctxt.sections();
+    let Some(workflows) = sections.next() else {
+        return Err(ParseError::end("", "some workflows").line(1));
+    };
+    let first = ctxt.line_of(workflows);
+    let rules: Rules = workflows
+        .lines()
+        .enumerate()
+        .map(|(n, line)| workflow(line).map_err(|e| e.line(first + n)))
+        .collect::<Result<_, _>>()?;
+
+    let mut parts = Vec::new();
+    for section in sections {
+        let first = ctxt.line_of(section);
+        for (n, line) in section.lines().enumerate() {
+            parts.push(line.parse().map_err(|e: ParseError| e.line(first + n))?);
         }
     }
-    println!("Total ratings of accepted parts: {sum}");
+    Ok((rules, parts))
 }
 
-pub fn b() {
-    let ctxt = readfile("19");
-    let mut rules: Rules = HashMap::new();
-
-    for line in ctxt.lines() {
-        if line.is_empty() {
-            break;
-        }
-        let (id, v) = parse(line);
-        rules.insert(id, v);
-    }
+pub fn a((rules, parts): &(Rules, Vec<Ratings>)) -> Number {
+    parts
+        .iter()
+        .copied()
+        .filter(|&part| accept(rules, part))
+        .map(|part| part.total())
+        .sum()
+}
 
-    let com = combinations(&rules);
-    println!("{com} distinct combinations of ratings are accepted");
+pub fn b((rules, _): &(Rules, Vec<Ratings>)) -> Number {
+    combinations(rules)
 }
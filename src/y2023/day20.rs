@@ -1,16 +1,16 @@
-use sky::readfile;
+use crate::{Contents, ParseError};
 
-type Number = u64;
+pub type Number = u64;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum Pulse {
+pub enum Pulse {
     Low,
     High,
 }
 
 #[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
-struct Id {
-    bytes: [u8; 2],
+pub struct Id {
+    pub bytes: [u8; 2],
 }
 
 use std::fmt::{Debug, Formatter};
@@ -31,7 +31,7 @@ impl Debug for Id {
 
 use std::str::FromStr;
 impl FromStr for Id {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s == "broadcaster" {
@@ -49,15 +49,15 @@ impl FromStr for Id {
             id.bytes.copy_from_slice(&s.as_bytes()[0..=1]);
             Ok(id)
         } else {
-            Err("Unsuitable identifier, should be two ASCII symbols")
+            Err(ParseError::new(s, s, "a module name"))
         }
     }
 }
 
 impl Id {
-    const BUTTON: Self = Self { bytes: [b'_'; 2] };
-    const BROADCAST: Self = Self { bytes: [0; 2] };
-    const RX: Self = Self {
+    pub const BUTTON: Self = Self { bytes: [b'_'; 2] };
+    pub const BROADCAST: Self = Self { bytes: [0; 2] };
+    pub const RX: Self = Self {
         bytes: [b'r', b'x'],
     };
 }
@@ -138,41 +138,42 @@ impl Module {
     }
 }
 
-fn parse(line: &str) -> (Id, Module) {
-    let (name, list) = line
-        .split_once(" -> ")
-        .expect("Every line should have an arrow");
-    let dest: Vec<Id> = list.split(", ").map(|n| n.parse::<Id>().unwrap()).collect();
+fn module(line: &str) -> Result<(Id, Module), ParseError> {
+    let id = |s: &str| s.parse::<Id>().map_err(|e| e.within(line, s));
+    let Some((name, list)) = line.split_once(" -> ") else {
+        return Err(ParseError::new(line, line, "a module then ->"));
+    };
+    let dest = list.split(", ").map(&id).collect::<Result<Vec<Id>, _>>()?;
     let src = Vec::new();
 
     if let Some(name) = name.strip_prefix('%') {
-        let id: Id = name.parse().unwrap();
-        return (
-            id,
+        return Ok((
+            id(name)?,
             Module {
                 kind: ModuleType::FlipFlop,
                 dest,
                 src,
                 state: 0,
             },
-        );
+        ));
     }
 
     if let Some(name) = name.strip_prefix('&') {
-        let id: Id = name.parse().unwrap();
-        return (
-            id,
+        return Ok((
+            id(name)?,
             Module {
                 kind: ModuleType::Conjunction,
                 dest,
                 src,
                 state: 0,
             },
-        );
+        ));
     }
 
-    assert!(name == "broadcaster");
-    (
+    if name != "broadcaster" {
+        return Err(ParseError::new(line, name, "%, & or broadcaster"));
+    }
+    Ok((
         Id::BROADCAST,
         Module {
             kind: ModuleType::Broadcast,
@@ -180,12 +181,13 @@ fn parse(line: &str) -> (Id, Module) {
             src,
             state: 0,
         },
-    )
+    ))
 }
 
 use std::collections::HashMap;
+/// Every module, wired together
 #[derive(Clone, Debug, Default)]
-struct System {
+pub struct System {
     modules: HashMap<Id, Module>,
 }
 
@@ -196,7 +198,8 @@ impl System {
         self.modules.insert(id, module);
     }
 
-    fn reset(&mut self) {
+    /// Return every module to its initial state
+    pub fn reset(&mut self) {
         for (_, module) in self.modules.iter_mut() {
             module.reset();
         }
@@ -219,7 +222,7 @@ impl System {
     }
 
     /// Vec of Ids which send to this target Id
-    fn by_target(&self, target: Id) -> Vec<Id> {
+    pub fn by_target(&self, target: Id) -> Vec<Id> {
         let mut sources = Vec::new();
 
         for (&source, module) in self.modules.iter() {
@@ -230,7 +233,8 @@ impl System {
         sources
     }
 
-    fn button(&mut self) -> (Number, Number) {
+    /// Push the button once, counting low and high pulses sent
+    pub fn button(&mut self) -> (Number, Number) {
         let mut todo: VecDeque<(Pulse, Id, Id)> = VecDeque::new();
         let mut low = 0;
         let mut high = 0;
@@ -258,7 +262,8 @@ impl System {
         (low, high)
     }
 
-    fn cycle(&mut self, check: Id) -> bool {
+    /// Push the button once, reporting whether the check module sent a high pulse
+    pub fn cycle(&mut self, check: Id) -> bool {
         let mut todo: VecDeque<(Pulse, Id, Id)> = VecDeque::new();
 
         // Begin with a single button push -> Low signal to Broadcaster
@@ -283,14 +288,18 @@ impl System {
     }
 }
 
-pub fn a() {
-    let ctxt = readfile("20");
+pub fn parse(ctxt: &Contents) -> Result<System, ParseError> {
     let mut sys: System = Default::default();
-    for line in ctxt.lines() {
-        let (id, module) = parse(line);
+    for (n, line) in ctxt.lines().enumerate() {
+        let (id, module) = module(line).map_err(|e| e.line(n + 1))?;
         sys.insert(id, module);
     }
     sys.init();
+    Ok(sys)
+}
+
+pub fn a(sys: &System) -> Number {
+    let mut sys = sys.clone();
     let mut low = 0;
     let mut high = 0;
     for _ in 0..1000 {
@@ -298,17 +307,11 @@ pub fn a() {
         low += l;
         high += h;
     }
-    println!("Low pulses x High pulses: {low}x{high} ={}", low * high);
+    low * high
 }
 
-pub fn b() {
-    let ctxt = readfile("20");
-    let mut sys: System = Default::default();
-    for line in ctxt.lines() {
-        let (id, module) = parse(line);
-        sys.insert(id, module);
-    }
-    sys.init();
+pub fn b(sys: &System) -> Number {
+    let mut sys = sys.clone();
 
     // find who signals 'rx'
     // trace that back to N senders
@@ -332,5 +335,5 @@ pub fn b() {
         panic!("No cycle detected for {id:?}");
     }
 
-    println!("After {cycle} buttom presses the 'rx' module gets a Low pulse");
+    cycle
 }
@@ -0,0 +1,137 @@
+use crate::map::Map;
+use crate::{Contents, ParseError};
+
+pub type Number = u32;
+
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+pub enum Plot {
+    #[default]
+    Outside,
+    Start,
+    Garden,
+    Rock,
+    Elf,
+}
+
+impl TryFrom<char> for Plot {
+    type Error = ParseError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        Ok(match ch {
+            'S' => Self::Start,
+            '.' => Self::Garden,
+            '#' => Self::Rock,
+            _ => return Err(ParseError::char(ch, "a plot, one of S.#")),
+        })
+    }
+}
+
+pub type Garden = Map<Plot>;
+
+use std::fmt::{Display, Formatter};
+
+impl Display for Plot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Outside => f.write_str("?"),
+            Self::Start => f.write_str("S"),
+            Self::Garden => f.write_str("."),
+            Self::Rock => f.write_str("#"),
+            Self::Elf => f.write_str("O"),
+        }
+    }
+}
+
+// Where the elf starts
+fn origin(map: &Garden) -> (isize, isize) {
+    let origin = map.find(|g| g == Plot::Start);
+    *origin
+        .first()
+        .expect("The map should show a starting location")
+}
+
+// How many plots the elf could reach from (x, y) in exactly n steps
+fn reach(map: &Garden, from: (isize, isize), n: u32) -> usize {
+    map.distances(from, |plot| plot != Plot::Rock)
+        .exactly(n)
+        .len()
+}
+
+/// Mark every plot the elf could reach in exactly n steps
+pub fn steps(map: &mut Garden, n: Number) {
+    let distances = map.distances(origin(map), |plot| plot != Plot::Rock);
+    for (x, y) in distances.exactly(n) {
+        map.write(x, y, Plot::Elf);
+    }
+}
+
+/// How many plots could be reached in exactly n steps on the infinitely repeating map, by
+/// walking every step, so only practical for small n but useful to check diamond
+pub fn tiled(map: &Garden, n: Number) -> usize {
+    map.tiled()
+        .distances(origin(map), |plot| plot != Plot::Rock, n)
+        .exactly(n)
+        .len()
+}
+
+pub fn parse(ctxt: &Contents) -> Result<Garden, ParseError> {
+    ctxt.grid()
+}
+
+pub fn a(map: &Garden) -> usize {
+    let mut map = map.clone();
+    steps(&mut map, 64);
+    map.count(|&&g| g == Plot::Elf)
+}
+
+/// How many plots could be reached in exactly radius steps on the infinitely repeating map
+pub fn diamond(map: &Garden, radius: usize) -> usize {
+    let tile_size = map.x().end() - map.x().start() + 1;
+    assert!(radius as isize > tile_size);
+
+    // Made up of repeated map tiles, whole tiles alternate between the plots at an odd or
+    // even distance from the start, and u32::MAX is far enough to reach every plot
+    let even = reach(map, origin(map), u32::MAX);
+    let odd = reach(map, origin(map), u32::MAX - 1);
+
+    let left = reach(map, (130, 65), 130);
+    let right = reach(map, (0, 65), 130);
+    let top = reach(map, (65, 130), 130);
+    let bottom = reach(map, (65, 0), 130);
+
+    let tls_small = reach(map, (130, 130), 64);
+    let bls_small = reach(map, (130, 0), 64);
+    let brs_small = reach(map, (0, 0), 64);
+    let trs_small = reach(map, (0, 130), 64);
+
+    let tls_large = reach(map, (130, 130), 195);
+    let bls_large = reach(map, (130, 0), 195);
+    let brs_large = reach(map, (0, 0), 195);
+    let trs_large = reach(map, (0, 130), 195);
+
+    let blocks = (radius - 65) / 131;
+    // Algorithm Only works for whole blocks
+    assert_eq!(blocks * 131, radius - 65);
+    let fewer = blocks - 1;
+
+    let edges = top
+        + (tls_small * blocks)
+        + (tls_large * fewer)
+        + left
+        + (bls_small * blocks)
+        + (bls_large * fewer)
+        + bottom
+        + (brs_small * blocks)
+        + (brs_large * fewer)
+        + right
+        + (trs_small * blocks)
+        + (trs_large) * fewer;
+
+    (fewer * fewer * even) + (blocks * blocks * odd) + edges
+}
+
+pub fn b(map: &Garden) -> usize {
+    const STEPS: usize = 26501365;
+
+    diamond(map, STEPS)
+}
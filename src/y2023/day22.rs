@@ -1,25 +1,25 @@
-use sky::map::Map;
-use sky::readfile;
+use crate::map::Map;
+use crate::{Contents, ParseError};
 
-type Number = u32;
+pub type Number = u32;
 
-type Coord = (u32, u32, u32);
+pub type Coord = (u32, u32, u32);
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum Direction {
+pub enum Direction {
     Vertical,
     X,
     Y,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-struct Brick {
-    from: Coord,
-    to: Coord,
+pub struct Brick {
+    pub from: Coord,
+    pub to: Coord,
 }
 
 impl Brick {
-    fn kind(&self) -> Direction {
+    pub fn kind(&self) -> Direction {
         if self.from.0 != self.to.0 {
             Direction::X
         } else if self.from.1 != self.to.1 {
@@ -44,23 +44,25 @@ impl PartialOrd for Brick {
     }
 }
 
-fn coord(s: &str) -> Coord {
-    let coords: Vec<Number> = s.split(',').filter_map(|n| n.parse().ok()).collect();
+fn coord(s: &str) -> Result<Coord, ParseError> {
+    let coords = crate::fields(s, ',').collect::<Result<Vec<Number>, _>>()?;
 
-    assert_eq!(coords.len(), 3);
-    (coords[0], coords[1], coords[2])
+    match coords[..] {
+        [x, y, z] => Ok((x, y, z)),
+        _ => Err(ParseError::new(s, s, "three coordinates")),
+    }
 }
 
 use std::str::FromStr;
 impl FromStr for Brick {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let Some((from, to)) = s.split_once('~') else {
-            return Err("Bricks should have ~ separator");
+            return Err(ParseError::new(s, s, "two corners separated by ~"));
         };
-        let from: Coord = coord(from);
-        let to: Coord = coord(to);
+        let from = coord(from).map_err(|e| e.within(s, from))?;
+        let to = coord(to).map_err(|e| e.within(s, to))?;
 
         Ok(Brick { from, to })
     }
@@ -68,7 +70,8 @@ impl FromStr for Brick {
 
 type Height = Map<Option<(usize, Number)>>;
 
-fn safe(bricks: &[Brick]) -> usize {
+/// How many of the sorted bricks could safely be disintegrated
+pub fn safe(bricks: &[Brick]) -> usize {
     let mut heights: Height = Map::new();
 
     let mut needed: Vec<usize> = Vec::with_capacity(bricks.len());
@@ -159,20 +162,19 @@ fn safe(bricks: &[Brick]) -> usize {
     bricks.len() - needed.len()
 }
 
-pub fn a() {
-    let ctxt = readfile("22");
-    let mut snapshot: Vec<Brick> = Vec::new();
-    for line in ctxt.lines() {
-        let brick: Brick = line.parse().expect("Not a brick");
-        snapshot.push(brick);
-    }
-    // Must sort snapshot first
+/// The snapshot of bricks, sorted from the ground upwards
+pub fn parse(ctxt: &Contents) -> Result<Vec<Brick>, ParseError> {
+    let mut snapshot: Vec<Brick> = ctxt.parsed()?;
     snapshot.sort();
-    let ok = safe(&snapshot);
-    println!("{ok} bricks can be safely chosen");
+    Ok(snapshot)
+}
+
+pub fn a(snapshot: &[Brick]) -> usize {
+    safe(snapshot)
 }
 
-fn what_if(bricks: &[Brick], except: &Brick) -> usize {
+/// How many other bricks would fall if this one were disintegrated
+pub fn what_if(bricks: &[Brick], except: &Brick) -> usize {
     let mut with: Map<Number> = Map::new();
     let mut without: Map<Number> = Map::new();
 
@@ -246,18 +248,10 @@ fn what_if(bricks: &[Brick], except: &Brick) -> usize {
     dropped
 }
 
-pub fn b() {
-    let ctxt = readfile("22");
-    let mut snapshot: Vec<Brick> = Vec::new();
-    for line in ctxt.lines() {
-        let brick: Brick = line.parse().expect("Not a brick");
-        snapshot.push(brick);
-    }
-    // Must sort snapshot first
-    snapshot.sort();
+pub fn b(snapshot: &[Brick]) -> usize {
     let mut total = 0;
     for brick in snapshot.iter() {
-        total += what_if(&snapshot, brick);
+        total += what_if(snapshot, brick);
     }
-    println!("{total}");
+    total
 }
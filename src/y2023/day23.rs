@@ -1,10 +1,10 @@
-use sky::map::Map;
-use sky::readfile;
+use crate::map::Map;
+use crate::{Contents, ParseError};
 
-type Number = u32;
+pub type Number = u32;
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
-enum Tile {
+pub enum Tile {
     #[default]
     Forest,
     Path,
@@ -14,21 +14,23 @@ enum Tile {
     SlopeLeft,
 }
 
-impl From<char> for Tile {
-    fn from(ch: char) -> Self {
-        match ch {
+impl TryFrom<char> for Tile {
+    type Error = ParseError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        Ok(match ch {
             '#' => Self::Forest,
             '.' => Self::Path,
             '^' => Self::SlopeUp,
             '>' => Self::SlopeRight,
             'v' => Self::SlopeDown,
             '<' => Self::SlopeLeft,
-            _ => panic!("{ch} should not appear in a trail map"),
-        }
+            _ => return Err(ParseError::char(ch, "a trail tile, one of #.^>v<")),
+        })
     }
 }
 
-type Trail = Map<Tile>;
+pub type Trail = Map<Tile>;
 
 use std::fmt::{Display, Formatter};
 
@@ -248,15 +250,30 @@ fn journeys(r: &[Route]) -> Vec<Number> {
     totals
 }
 
-pub fn a() {
-    let ctxt = readfile("23");
-    let trail: Trail = ctxt.value().parse().unwrap();
-    let r = routes(&trail);
+pub fn parse(ctxt: &Contents) -> Result<Trail, ParseError> {
+    ctxt.grid()
+}
+
+/// Longest hike down the slippery slopes
+pub fn longest(trail: &Trail) -> Number {
+    let r = routes(trail);
     let totals = journeys(&r);
     let best = totals
         .last()
         .expect("At least one possible route should exist");
-    println!("The longest hike is {best} steps long");
+    *best
+}
+
+/// Longest hike if the slopes are just paths
+pub fn longest_dry(trail: &Trail) -> Number {
+    let mut trail = trail.clone();
+    remove_slopes(&mut trail);
+    let rts = new_routes(&trail);
+    new_journeys(&rts)
+}
+
+pub fn a(trail: &Trail) -> Number {
+    longest(trail)
 }
 
 fn remove_slopes(trail: &mut Trail) {
@@ -460,7 +477,7 @@ impl Wanderer {
         v
     }
 
-    /// _Maximum_ steps from start to end
+    /// _Maximum_ steps from start to end, giving up if the current Budget is exceeded
     fn furthest(start: NodeId, end: NodeId, graph: &NewGraph) -> Number {
         if start == end {
             return 0;
@@ -472,7 +489,13 @@ impl Wanderer {
         // Cheating with a 1 here
         options.push(Self::new(1, start, start));
 
+        let budget = crate::Budget::current();
+        let mut depth = 0;
         while !options.is_empty() {
+            if let Err(limit) = budget.check(best.len(), depth) {
+                panic!("Gave up finding the longest hike: the search exceeded {limit}");
+            }
+            depth += 1;
             let mut tomorrow: Vec<Self> = Vec::new();
             while let Some(opt) = options.pop() {
                 let steps = best.entry((opt.at, opt.seen)).or_default();
@@ -534,11 +557,6 @@ fn new_journeys(r: &[Route]) -> Number {
     Wanderer::furthest(start, end, &graph) - 2
 }
 
-pub fn b() {
-    let ctxt = readfile("23");
-    let mut trail: Trail = ctxt.value().parse().unwrap();
-    remove_slopes(&mut trail);
-    let rts = new_routes(&trail);
-    let steps = new_journeys(&rts);
-    println!("Longest surprisingly dry hike is {steps} steps");
+pub fn b(trail: &Trail) -> Number {
+    longest_dry(trail)
 }
@@ -1,19 +1,22 @@
-use sky::readfile;
+use crate::{Contents, ParseError};
 use std::ops::RangeInclusive;
 
 // Several concepts for part II cribbed from /u/TheZigerionScammer in Reddit's r/adventofcode
 
-type Number = i64;
+pub type Number = i64;
 
 const TEST: RangeInclusive<Number> = 200000000000000..=400000000000000;
 
-type Coord = (Number, Number, Number);
-type Velocity = (Number, Number, Number);
+/// The much smaller test area for the example in the puzzle
+const EXAMPLE: RangeInclusive<Number> = 7..=27;
+
+pub type Coord = (Number, Number, Number);
+pub type Velocity = (Number, Number, Number);
 
 #[derive(Copy, Clone, Debug)]
-struct Hailstone {
-    start: Coord,
-    velocity: Velocity,
+pub struct Hailstone {
+    pub start: Coord,
+    pub velocity: Velocity,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -58,7 +61,8 @@ impl Hailstone {
         Intersect::At(x, y)
     }
 
-    fn hits(&self, other: &Self) -> bool {
+    /// Whether the paths of these hailstones cross inside the test area, ignoring Z
+    pub fn hits(&self, other: &Self, test: &RangeInclusive<Number>) -> bool {
         match self.hit_at_2d(other) {
             Intersect::Never => false,
             Intersect::Always => {
@@ -70,7 +74,7 @@ impl Hailstone {
                 let x = x as Number;
                 let y = y as Number;
                 // Check the hit happens in the test area
-                if !TEST.contains(&x) || !TEST.contains(&y) {
+                if !test.contains(&x) || !test.contains(&y) {
                     return false;
                 }
 
@@ -93,45 +97,58 @@ impl Hailstone {
     }
 }
 
-fn triple(s: &str) -> (Number, Number, Number) {
-    let v: Vec<Number> = s
-        .split(", ")
-        .filter_map(|s| s.trim().parse().ok())
-        .collect();
-    assert_eq!(v.len(), 3);
-    (v[0], v[1], v[2])
+fn triple(s: &str) -> Result<(Number, Number, Number), ParseError> {
+    let v = crate::fields(s, ',').collect::<Result<Vec<Number>, _>>()?;
+    match v[..] {
+        [x, y, z] => Ok((x, y, z)),
+        _ => Err(ParseError::new(s, s, "three numbers")),
+    }
 }
 
 use std::str::FromStr;
 impl FromStr for Hailstone {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let Some((start, velocity)) = s.split_once(" @ ") else {
-            return Err("No @ symbol separating position from velocity");
+            return Err(ParseError::new(s, s, "position @ velocity"));
         };
-        let start = triple(start);
-        let velocity = triple(velocity);
+        let start = triple(start).map_err(|e| e.within(s, start))?;
+        let velocity = triple(velocity).map_err(|e| e.within(s, velocity))?;
         Ok(Hailstone { start, velocity })
     }
 }
 
-pub fn a() {
-    let ctxt = readfile("24");
-    let mut stones: Vec<Hailstone> = ctxt.lines().filter_map(|s| s.parse().ok()).collect();
+pub fn parse(ctxt: &Contents) -> Result<Vec<Hailstone>, ParseError> {
+    ctxt.parsed()
+}
+
+/// How many pairs of hailstones have paths which cross inside the test area
+pub fn crossings(stones: &[Hailstone], test: RangeInclusive<Number>) -> usize {
+    let mut stones = stones.to_vec();
 
     let mut hits = 0;
     while let Some(stone) = stones.pop() {
         for other in stones.iter() {
-            if stone.hits(other) {
+            if stone.hits(other, &test) {
                 hits += 1;
             }
         }
     }
-    println!("{hits} intersections occur in the test area on future paths");
+    hits
+}
+
+pub fn a(stones: &[Hailstone]) -> usize {
+    crossings(stones, TEST)
+}
+
+/// Part a for the example, which has a different test area
+pub fn a_example(stones: &[Hailstone]) -> usize {
+    crossings(stones, EXAMPLE)
 }
 
-fn velocity(stones: &[Hailstone]) -> Velocity {
+/// Velocity of a rock thrown to hit every hailstone
+pub fn velocity(stones: &[Hailstone]) -> Velocity {
     let mut sorted = Vec::new();
     sorted.extend_from_slice(stones);
     sorted.sort_unstable_by_key(|stone| stone.velocity.0);
@@ -141,8 +158,8 @@ fn velocity(stones: &[Hailstone]) -> Velocity {
         let mut old: Option<Hailstone> = None;
         for stone in sorted.iter() {
             let vel = stone.velocity.0;
-            if old.is_some() && old.unwrap().velocity.0 == vel {
-                let dist_diff = stone.start.0 - old.unwrap().start.0;
+            if let Some(old) = old.filter(|old| old.velocity.0 == vel) {
+                let dist_diff = stone.start.0 - old.start.0;
                 let vel_diff = guess - vel;
                 if vel_diff != 0 && dist_diff % vel_diff != 0 {
                     continue 'outer;
@@ -161,8 +178,8 @@ fn velocity(stones: &[Hailstone]) -> Velocity {
         let mut old: Option<Hailstone> = None;
         for stone in sorted.iter() {
             let vel = stone.velocity.1;
-            if old.is_some() && old.unwrap().velocity.1 == vel {
-                let dist_diff = stone.start.1 - old.unwrap().start.1;
+            if let Some(old) = old.filter(|old| old.velocity.1 == vel) {
+                let dist_diff = stone.start.1 - old.start.1;
                 let vel_diff = guess - vel;
                 if vel_diff != 0 && dist_diff % vel_diff != 0 {
                     continue 'outer;
@@ -181,8 +198,8 @@ fn velocity(stones: &[Hailstone]) -> Velocity {
         let mut old: Option<Hailstone> = None;
         for stone in sorted.iter() {
             let vel = stone.velocity.2;
-            if old.is_some() && old.unwrap().velocity.2 == vel {
-                let dist_diff = stone.start.2 - old.unwrap().start.2;
+            if let Some(old) = old.filter(|old| old.velocity.2 == vel) {
+                let dist_diff = stone.start.2 - old.start.2;
                 let vel_diff = guess - vel;
                 if vel_diff != 0 && dist_diff % vel_diff != 0 {
                     continue 'outer;
@@ -252,7 +269,8 @@ impl Rational {
     }
 }
 
-fn position(stones: &[Hailstone], vel: Velocity) -> Coord {
+/// Where to throw a rock from, at this velocity, to hit every hailstone
+pub fn position(stones: &[Hailstone], vel: Velocity) -> Coord {
     let mut modified = Vec::new();
     modified.extend_from_slice(stones);
     for stone in modified.iter_mut() {
@@ -300,13 +318,8 @@ fn position(stones: &[Hailstone], vel: Velocity) -> Coord {
     panic!("Stones exhausted without discovering a solution");
 }
 
-pub fn b() {
-    let ctxt = readfile("24");
-    let stones: Vec<Hailstone> = ctxt.lines().filter_map(|s| s.parse().ok()).collect();
-    let vel = velocity(&stones);
-    let pos = position(&stones, vel);
-    println!(
-        "3D velocities are {vel:?}. 3D start position is {pos:?}. Therefore puzzle answer is {}",
-        pos.0 + pos.1 + pos.2
-    );
+pub fn b(stones: &[Hailstone]) -> Number {
+    let vel = velocity(stones);
+    let pos = position(stones, vel);
+    pos.0 + pos.1 + pos.2
 }
@@ -1,4 +1,4 @@
-use sky::readfile;
+use crate::{Contents, ParseError};
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -13,16 +13,19 @@ fn extra(done: &HashSet<&str>, hm: &HashMap<&str, usize>, links: &[&str]) -> usi
 }
 
 fn clinks(hm: &HashMap<&str, usize>) -> usize {
-    hm.iter().map(|(_, v)| v).sum()
+    hm.values().sum()
 }
 
-pub fn a() {
-    let ctxt = readfile("25");
-    let mut components: HashMap<&str, Vec<&str>> = HashMap::new();
-    for line in ctxt.lines() {
-        let (fto, rest) = line
-            .split_once(": ")
-            .expect("Line should have ': ' separator");
+/// Each component and the others it is wired to
+pub type Components<'t> = HashMap<&'t str, Vec<&'t str>>;
+
+pub fn parse(ctxt: &Contents) -> Result<Components<'_>, ParseError> {
+    let mut components: Components = HashMap::new();
+    for (n, line) in ctxt.lines().enumerate() {
+        let Some((fto, rest)) = line.split_once(": ") else {
+            let expected = "a component, then : and the others";
+            return Err(ParseError::new(line, line, expected).line(n + 1));
+        };
         for node in rest.split(' ') {
             let entry = components.entry(fto).or_default();
             entry.push(node);
@@ -32,7 +35,11 @@ pub fn a() {
             entry.sort();
         }
     }
+    Ok(components)
+}
 
+/// Sizes of the two groups left after cutting the three wires between them
+pub fn split(components: &Components) -> (usize, usize) {
     let keys: Vec<&str> = components.keys().copied().collect();
     let chosen = keys.first().unwrap();
     let mut mine: HashSet<&str> = HashSet::new();
@@ -66,13 +73,14 @@ pub fn a() {
         }
     }
     let a = mine.len();
-    let b = keys.len() - a;
-    println!(
-        "Sizes of each group multiplied together: {a} x {b} = {}",
-        a * b
-    );
+    (a, keys.len() - a)
+}
+
+pub fn a(components: &Components) -> usize {
+    let (a, b) = split(components);
+    a * b
 }
 
-pub fn b() {
-    println!("Happy Christmas!");
+pub fn b(_components: &Components) -> &'static str {
+    "Happy Christmas!"
 }